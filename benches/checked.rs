@@ -0,0 +1,121 @@
+//! Checked NzInt/NzFloat arithmetic against the raw i64/f64 operations it wraps.
+//! Run with `cargo bench`; each group folds a slice so the loop body is the operation itself.
+//! Expect parity for mul/div; a raw i64 add fold vectorizes, which a per-step zero test prevents.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use nz::{NzFloat, NzInt};
+
+const N: usize = 4096;
+
+/// Positive odd inputs: running sums stay positive and wrapped products stay odd, so a fold
+/// never short-circuits on a zero result.
+fn ints() -> Vec<i64> {
+    (0..N as i64).map(|i| (i * 7919 % 1000) | 1).collect()
+}
+
+/// Values near 1 and their reciprocals in turn, so running products stay finite and normal.
+fn floats() -> Vec<f64> {
+    (0..N)
+        .map(|i| {
+            let x = 1.0 + (i % 97) as f64 / 128.0;
+            if i % 2 == 0 { x } else { 1.0 / x }
+        })
+        .collect()
+}
+
+fn bench_int(c: &mut Criterion) {
+    let raw = ints();
+    let nz: Vec<NzInt> = raw.iter().map(|&v| NzInt::new(v).unwrap()).collect();
+    let mut g = c.benchmark_group("int");
+    g.throughput(Throughput::Elements(N as u64));
+
+    g.bench_function(BenchmarkId::new("add", "i64"), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .fold(1i64, |acc, &x| acc.wrapping_add(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("add", "NzInt"), |b| {
+        b.iter(|| {
+            black_box(&nz)
+                .iter()
+                .try_fold(NzInt::one(), |acc, &x| acc.checked_add(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("mul", "i64"), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .fold(1i64, |acc, &x| acc.wrapping_mul(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("mul", "NzInt"), |b| {
+        b.iter(|| {
+            black_box(&nz)
+                .iter()
+                .try_fold(NzInt::one(), |acc, &x| acc.checked_mul(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("div", "i64"), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .map(|&x| i64::MAX / x)
+                .fold(0i64, i64::wrapping_add)
+        })
+    });
+    let max = NzInt::new(i64::MAX).unwrap();
+    g.bench_function(BenchmarkId::new("div", "NzInt"), |b| {
+        b.iter(|| {
+            black_box(&nz).iter().try_fold(0i64, |acc, &x| {
+                max.checked_div(x).map(|q| acc.wrapping_add(q.get()))
+            })
+        })
+    });
+    g.finish();
+}
+
+fn bench_float(c: &mut Criterion) {
+    let raw = floats();
+    let nz: Vec<NzFloat> = raw.iter().map(|&v| NzFloat::new(v).unwrap()).collect();
+    let mut g = c.benchmark_group("float");
+    g.throughput(Throughput::Elements(N as u64));
+
+    g.bench_function(BenchmarkId::new("add", "f64"), |b| {
+        b.iter(|| black_box(&raw).iter().fold(1.0f64, |acc, &x| acc + x))
+    });
+    g.bench_function(BenchmarkId::new("add", "NzFloat"), |b| {
+        b.iter(|| {
+            black_box(&nz)
+                .iter()
+                .try_fold(NzFloat::one(), |acc, &x| acc.checked_add(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("mul", "f64"), |b| {
+        b.iter(|| black_box(&raw).iter().fold(1.0f64, |acc, &x| acc * x))
+    });
+    g.bench_function(BenchmarkId::new("mul", "NzFloat"), |b| {
+        b.iter(|| {
+            black_box(&nz)
+                .iter()
+                .try_fold(NzFloat::one(), |acc, &x| acc.checked_mul(x))
+        })
+    });
+    g.bench_function(BenchmarkId::new("div", "f64"), |b| {
+        b.iter(|| black_box(&raw).iter().fold(1.0f64, |acc, &x| acc / x))
+    });
+    g.bench_function(BenchmarkId::new("div", "NzFloat"), |b| {
+        b.iter(|| {
+            black_box(&nz)
+                .iter()
+                .try_fold(NzFloat::one(), |acc, &x| acc.checked_div(x))
+        })
+    });
+    g.finish();
+}
+
+criterion_group!(benches, bench_int, bench_float);
+criterion_main!(benches);
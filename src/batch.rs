@@ -0,0 +1,264 @@
+//! batch: Element-wise arithmetic over slices of NzInt / NzFloat
+//! API:
+//! - add_slices/sub_slices/mul_slices/div_slices(&[T], &[T]) -> Result<Vec<T>, BatchError<E>>
+//!   for T = NzInt (E = NzError) and T = NzFloat (E = NzfError)
+//! - split_signs(&[NzFloat]) -> (NzSignVec, Vec<PzFloat>) and its inverse recombine(&NzSignVec, &[PzFloat])
+//!
+//! Design choices:
+//! - NzInt/NzFloat are repr(transparent), so inputs are read as &[i64] / &[f64] and the
+//!   output buffer is built raw: one branch-free loop the compiler can vectorize, then one
+//!   scan for the first invalid element, instead of a checked_* call per element
+//! - Integer semantics match NzInt::checked_*: wrapping, with zero as the only add/sub/mul error
+//! - BatchError is NzStepError: the index of the first failing element and the reason
+//! - Sign splitting is pure bit manipulation (sign bit out, sign bit in), so it needs no
+//!   validation pass; NzSignVec packs 64 signs per u64 word
+
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+use crate::nziter::NzStepError;
+use crate::nzpositive::PzFloat;
+use crate::nzsignvec::NzSignVec;
+
+/// Index and reason of the first element whose result is invalid.
+pub type BatchError<E> = NzStepError<E>;
+
+/// Element types supported by the batch functions.
+pub trait BatchElem: Copy + sealed::Sealed {
+    type Error;
+    #[doc(hidden)]
+    fn batch_add(a: &[Self], b: &[Self]) -> Result<Vec<Self>, BatchError<Self::Error>>;
+    #[doc(hidden)]
+    fn batch_sub(a: &[Self], b: &[Self]) -> Result<Vec<Self>, BatchError<Self::Error>>;
+    #[doc(hidden)]
+    fn batch_mul(a: &[Self], b: &[Self]) -> Result<Vec<Self>, BatchError<Self::Error>>;
+    #[doc(hidden)]
+    fn batch_div(a: &[Self], b: &[Self]) -> Result<Vec<Self>, BatchError<Self::Error>>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for crate::nzint::NzInt {}
+    impl Sealed for crate::nzfloat::NzFloat {}
+}
+
+/// Element-wise a + b.
+/// # Panics
+/// Panics if the slices differ in length.
+#[inline]
+pub fn add_slices<T: BatchElem>(a: &[T], b: &[T]) -> Result<Vec<T>, BatchError<T::Error>> {
+    assert_eq!(a.len(), b.len(), "add_slices: length mismatch");
+    T::batch_add(a, b)
+}
+
+/// Element-wise a - b.
+/// # Panics
+/// Panics if the slices differ in length.
+#[inline]
+pub fn sub_slices<T: BatchElem>(a: &[T], b: &[T]) -> Result<Vec<T>, BatchError<T::Error>> {
+    assert_eq!(a.len(), b.len(), "sub_slices: length mismatch");
+    T::batch_sub(a, b)
+}
+
+/// Element-wise a * b.
+/// # Panics
+/// Panics if the slices differ in length.
+#[inline]
+pub fn mul_slices<T: BatchElem>(a: &[T], b: &[T]) -> Result<Vec<T>, BatchError<T::Error>> {
+    assert_eq!(a.len(), b.len(), "mul_slices: length mismatch");
+    T::batch_mul(a, b)
+}
+
+/// Element-wise a / b (integers truncate toward zero; the divisor is never zero).
+/// # Panics
+/// Panics if the slices differ in length.
+#[inline]
+pub fn div_slices<T: BatchElem>(a: &[T], b: &[T]) -> Result<Vec<T>, BatchError<T::Error>> {
+    assert_eq!(a.len(), b.len(), "div_slices: length mismatch");
+    T::batch_div(a, b)
+}
+
+#[inline]
+fn raw_ints(s: &[NzInt]) -> &[i64] {
+    // NzInt is repr(transparent) over NonZeroI64, which has the layout of i64.
+    unsafe { core::slice::from_raw_parts(s.as_ptr().cast::<i64>(), s.len()) }
+}
+
+#[inline]
+fn raw_floats(s: &[NzFloat]) -> &[f64] {
+    // NzFloat is repr(transparent) over f64.
+    unsafe { core::slice::from_raw_parts(s.as_ptr().cast::<f64>(), s.len()) }
+}
+
+/// Reinterpret a fully validated raw buffer. Caller guarantees every element satisfies T's invariant
+/// and that T is repr(transparent) over R.
+#[inline]
+unsafe fn cast_vec<R, T>(v: Vec<R>) -> Vec<T> {
+    let mut v = ManuallyDrop::new(v);
+    unsafe { Vec::from_raw_parts(v.as_mut_ptr().cast::<T>(), v.len(), v.capacity()) }
+}
+
+#[inline]
+fn finish_ints(v: Vec<i64>) -> Result<Vec<NzInt>, BatchError<NzError>> {
+    match v.iter().position(|&x| x == 0) {
+        Some(index) => Err(BatchError {
+            index,
+            error: NzError::ZeroResult,
+        }),
+        None => Ok(unsafe { cast_vec(v) }),
+    }
+}
+
+#[inline]
+fn finish_floats(v: Vec<f64>) -> Result<Vec<NzFloat>, BatchError<NzfError>> {
+    match v
+        .iter()
+        .enumerate()
+        .find_map(|(index, &x)| NzFloat::check(x).err().map(|error| (index, error)))
+    {
+        Some((index, error)) => Err(BatchError { index, error }),
+        None => Ok(unsafe { cast_vec(v) }),
+    }
+}
+
+impl BatchElem for NzInt {
+    type Error = NzError;
+
+    fn batch_add(a: &[NzInt], b: &[NzInt]) -> Result<Vec<NzInt>, BatchError<NzError>> {
+        finish_ints(
+            raw_ints(a)
+                .iter()
+                .zip(raw_ints(b))
+                .map(|(x, y)| x.wrapping_add(*y))
+                .collect(),
+        )
+    }
+
+    fn batch_sub(a: &[NzInt], b: &[NzInt]) -> Result<Vec<NzInt>, BatchError<NzError>> {
+        finish_ints(
+            raw_ints(a)
+                .iter()
+                .zip(raw_ints(b))
+                .map(|(x, y)| x.wrapping_sub(*y))
+                .collect(),
+        )
+    }
+
+    fn batch_mul(a: &[NzInt], b: &[NzInt]) -> Result<Vec<NzInt>, BatchError<NzError>> {
+        finish_ints(
+            raw_ints(a)
+                .iter()
+                .zip(raw_ints(b))
+                .map(|(x, y)| x.wrapping_mul(*y))
+                .collect(),
+        )
+    }
+
+    fn batch_div(a: &[NzInt], b: &[NzInt]) -> Result<Vec<NzInt>, BatchError<NzError>> {
+        // Integer division does not vectorize; validate in the same pass.
+        let mut out = Vec::with_capacity(a.len());
+        for (index, (&x, &y)) in raw_ints(a).iter().zip(raw_ints(b)).enumerate() {
+            let q = x.checked_div(y).ok_or(BatchError {
+                index,
+                error: NzError::DivOverflow,
+            })?;
+            if q == 0 {
+                return Err(BatchError {
+                    index,
+                    error: NzError::ZeroResult,
+                });
+            }
+            out.push(q);
+        }
+        Ok(unsafe { cast_vec(out) })
+    }
+}
+
+impl BatchElem for NzFloat {
+    type Error = NzfError;
+
+    fn batch_add(a: &[NzFloat], b: &[NzFloat]) -> Result<Vec<NzFloat>, BatchError<NzfError>> {
+        finish_floats(
+            raw_floats(a)
+                .iter()
+                .zip(raw_floats(b))
+                .map(|(x, y)| x + y)
+                .collect(),
+        )
+    }
+
+    fn batch_sub(a: &[NzFloat], b: &[NzFloat]) -> Result<Vec<NzFloat>, BatchError<NzfError>> {
+        finish_floats(
+            raw_floats(a)
+                .iter()
+                .zip(raw_floats(b))
+                .map(|(x, y)| x - y)
+                .collect(),
+        )
+    }
+
+    fn batch_mul(a: &[NzFloat], b: &[NzFloat]) -> Result<Vec<NzFloat>, BatchError<NzfError>> {
+        finish_floats(
+            raw_floats(a)
+                .iter()
+                .zip(raw_floats(b))
+                .map(|(x, y)| x * y)
+                .collect(),
+        )
+    }
+
+    fn batch_div(a: &[NzFloat], b: &[NzFloat]) -> Result<Vec<NzFloat>, BatchError<NzfError>> {
+        finish_floats(
+            raw_floats(a)
+                .iter()
+                .zip(raw_floats(b))
+                .map(|(x, y)| x / y)
+                .collect(),
+        )
+    }
+}
+
+/* ----- Sign/magnitude split ----- */
+
+/// Split every value into its sign and magnitude in one pass; total.
+#[inline]
+pub fn split_signs(xs: &[NzFloat]) -> (NzSignVec, Vec<PzFloat>) {
+    let raw = raw_floats(xs);
+    // Branch-free per word so the compiler can vectorize the inner loops.
+    let words = raw
+        .chunks(64)
+        .map(|c| {
+            c.iter()
+                .enumerate()
+                .fold(0u64, |w, (i, x)| w | (x.to_bits() >> 63) << i)
+        })
+        .collect();
+    let mags: Vec<f64> = raw.iter().map(|x| x.abs()).collect();
+    // |x| of a non-zero, non-NaN value is in (0, +inf]; PzFloat is repr(transparent) over f64.
+    (NzSignVec::from_words(words, xs.len()), unsafe {
+        cast_vec(mags)
+    })
+}
+
+/// Inverse of split_signs: give each magnitude its sign; total.
+/// # Panics
+/// Panics if `signs` and `mags` differ in length.
+#[inline]
+pub fn recombine(signs: &NzSignVec, mags: &[PzFloat]) -> Vec<NzFloat> {
+    assert_eq!(signs.len(), mags.len(), "recombine: length mismatch");
+    // PzFloat is repr(transparent) over f64.
+    let raw = unsafe { core::slice::from_raw_parts(mags.as_ptr().cast::<f64>(), mags.len()) };
+    let mut out: Vec<f64> = Vec::with_capacity(raw.len());
+    for (chunk, &w) in raw.chunks(64).zip(signs.as_words()) {
+        out.extend(
+            chunk
+                .iter()
+                .enumerate()
+                .map(|(i, m)| f64::from_bits(m.to_bits() | (w >> i & 1) << 63)),
+        );
+    }
+    // Setting the sign bit of a positive, non-NaN value keeps it non-zero and non-NaN.
+    unsafe { cast_vec(out) }
+}
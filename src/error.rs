@@ -0,0 +1,65 @@
+//! error: Crate-level error type
+//! Design choices:
+//! - Wraps the per-type errors so mixed int/float code can use `?` with a single type
+//! - Implements core::error::Error (== std::error::Error), so it composes with anyhow etc.
+//! - Display already includes the wrapped error ("integer error: result is zero"), so source()
+//!   is None; returning it as well would make chain reporters print the message twice
+
+use core::fmt;
+
+use crate::nzfloat::{NzfError, NzfParseError};
+use crate::nzint::NzError;
+use crate::nzparse::NzParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// An NzInt (or other integer type) operation failed.
+    Int(NzError),
+    /// An NzFloat operation failed.
+    Float(NzfError),
+    /// A raw f64 was rejected when constructing an NzFloat.
+    FloatInput(NzfParseError),
+    /// Parsing text into an nz type failed.
+    Parse(NzParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Int(e) => write!(f, "integer error: {e}"),
+            Error::Float(e) => write!(f, "float error: {e}"),
+            Error::FloatInput(e) => write!(f, "invalid float: {e}"),
+            Error::Parse(e) => write!(f, "parse error: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<NzError> for Error {
+    #[inline]
+    fn from(e: NzError) -> Self {
+        Error::Int(e)
+    }
+}
+
+impl From<NzfError> for Error {
+    #[inline]
+    fn from(e: NzfError) -> Self {
+        Error::Float(e)
+    }
+}
+
+impl From<NzfParseError> for Error {
+    #[inline]
+    fn from(e: NzfParseError) -> Self {
+        Error::FloatInput(e)
+    }
+}
+
+impl From<NzParseError> for Error {
+    #[inline]
+    fn from(e: NzParseError) -> Self {
+        Error::Parse(e)
+    }
+}
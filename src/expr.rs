@@ -0,0 +1,336 @@
+//! expr: Infix expression front-end for the vm
+//! API:
+//! - eval(src) -> Result<NzValue, ExprError>, e.g. eval("3 * (7 - 2) / -5") == Int(-3)
+//! - eval_with(src, vars): identifiers are looked up in `vars` (name, value) pairs
+//! - compile(src, vars) -> Result<(Vec<Instr>, Vec<Range<usize>>), ExprError>: the vm program
+//!   and, for each instruction, the byte range of the sub-expression it computes
+//! - ExprError { span, kind }: `&src[span]` is the failing sub-expression
+//! - explain(src, vars): also the ExprTrace tree (span, value, operands) of what was computed
+//!
+//! Grammar:
+//!   expr    := term (('+' | '-') term)*
+//!   term    := unary (('*' | '/') unary)*
+//!   unary   := ('-' | '+') unary | primary
+//!   primary := number | identifier | '(' expr ')'
+//!   identifier := [A-Za-z_][A-Za-z0-9_.]*
+//!
+//! Design choices:
+//! - Integer literals are NzInt and literals with '.' or an exponent are NzFloat, so the
+//!   vm's int/float rules apply (7 / 2 == 3, 7 / 2.0 == 3.5); a literal zero is rejected
+//!   while parsing, before anything runs
+//! - A minus sign directly before a literal is part of it (so -9223372036854775808 parses);
+//!   any other negation compiles to a multiplication by -1
+//! - The expression is evaluated by vm::run, so a zero result reports the instruction's
+//!   span: for "2 * (3 - 3)" that is "3 - 3"
+//! - Identifiers may contain '.', so the dotted paths nzconfig lowers a config tree to
+//!   (limits.max, servers.0.weight) are variable names as they stand
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::vm::{self, Instr, NzValue, VmFault};
+
+/// What went wrong; see ExprError::span for where.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExprErrorKind {
+    /// Unexpected character or token.
+    Syntax,
+    /// The input ended in the middle of an expression.
+    UnexpectedEnd,
+    /// A literal that is zero.
+    ZeroLiteral,
+    /// A malformed or out-of-range numeric literal.
+    BadLiteral,
+    /// An identifier with no binding.
+    UnknownVariable,
+    /// Evaluation failed (e.g., the sub-expression is zero).
+    Eval(VmFault),
+}
+
+/// Error with the byte range of the offending token or sub-expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    pub span: Range<usize>,
+    pub kind: ExprErrorKind,
+}
+
+impl fmt::Display for ExprErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprErrorKind::Syntax => f.write_str("syntax error"),
+            ExprErrorKind::UnexpectedEnd => f.write_str("unexpected end of input"),
+            ExprErrorKind::ZeroLiteral => f.write_str("literal is zero"),
+            ExprErrorKind::BadLiteral => f.write_str("invalid numeric literal"),
+            ExprErrorKind::UnknownVariable => f.write_str("unknown variable"),
+            ExprErrorKind::Eval(e) => e.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl core::error::Error for ExprError {}
+
+/// Evaluate an expression without variables.
+#[inline]
+pub fn eval(src: &str) -> Result<NzValue, ExprError> {
+    eval_with(src, &[])
+}
+
+/// Evaluate an expression; identifiers are looked up in `vars`.
+pub fn eval_with(src: &str, vars: &[(&str, NzValue)]) -> Result<NzValue, ExprError> {
+    let (code, spans) = compile(src, vars)?;
+    vm::run(&code).map_err(|e| ExprError {
+        span: spans[e.index].clone(),
+        kind: ExprErrorKind::Eval(e.error),
+    })
+}
+
+/// A computed sub-expression: its source span, value, and the operands it was computed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExprTrace {
+    pub span: Range<usize>,
+    pub value: NzValue,
+    pub children: Vec<ExprTrace>,
+}
+
+/// Evaluate like eval_with and also return how: on success the tree of the whole expression,
+/// on an evaluation error the sub-expressions that had been computed, left to right (the last
+/// ones are the operands of the failing operation). Syntax errors have no trace.
+pub fn explain(
+    src: &str,
+    vars: &[(&str, NzValue)],
+) -> Result<(Vec<ExprTrace>, Result<NzValue, ExprError>), ExprError> {
+    let (code, spans) = compile(src, vars)?;
+    let (steps, result) = vm::run_traced(&code, usize::MAX);
+    // Expression programs have no jumps: every step pushes, and binary operations pop two.
+    let mut nodes: Vec<ExprTrace> = Vec::new();
+    for step in steps {
+        let children = match code[step.index] {
+            Instr::Add | Instr::Sub | Instr::Mul | Instr::Div => nodes.split_off(nodes.len() - 2),
+            _ => Vec::new(),
+        };
+        if let Some(value) = step.pushed {
+            nodes.push(ExprTrace {
+                span: spans[step.index].clone(),
+                value,
+                children,
+            });
+        }
+    }
+    let result = result.map_err(|e| ExprError {
+        span: spans[e.index].clone(),
+        kind: ExprErrorKind::Eval(e.error),
+    });
+    Ok((nodes, result))
+}
+
+/// Compile to a vm program plus the source span of each instruction.
+pub fn compile(
+    src: &str,
+    vars: &[(&str, NzValue)],
+) -> Result<(Vec<Instr>, Vec<Range<usize>>), ExprError> {
+    let mut p = Parser {
+        src,
+        pos: 0,
+        vars,
+        code: Vec::new(),
+        spans: Vec::new(),
+    };
+    p.expr()?;
+    p.skip_ws();
+    if p.pos < src.len() {
+        return Err(p.error(p.pos..p.pos + 1, ExprErrorKind::Syntax));
+    }
+    Ok((p.code, p.spans))
+}
+
+struct Parser<'a, 'v> {
+    src: &'a str,
+    pos: usize,
+    vars: &'v [(&'v str, NzValue)],
+    code: Vec<Instr>,
+    spans: Vec<Range<usize>>,
+}
+
+impl Parser<'_, '_> {
+    fn expr(&mut self) -> Result<Range<usize>, ExprError> {
+        let mut lhs = self.term()?;
+        while let Some(op @ (b'+' | b'-')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = lhs.start..rhs.end;
+            self.emit(
+                if op == b'+' { Instr::Add } else { Instr::Sub },
+                lhs.clone(),
+            );
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Range<usize>, ExprError> {
+        let mut lhs = self.unary()?;
+        while let Some(op @ (b'*' | b'/')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = lhs.start..rhs.end;
+            self.emit(
+                if op == b'*' { Instr::Mul } else { Instr::Div },
+                lhs.clone(),
+            );
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Range<usize>, ExprError> {
+        let start = self.pos_after_ws();
+        match self.peek() {
+            Some(b'+') => {
+                self.pos += 1;
+                let inner = self.unary()?;
+                Ok(start..inner.end)
+            }
+            Some(b'-') => {
+                self.pos += 1;
+                if self.peek().is_some_and(|c| c.is_ascii_digit() || c == b'.') {
+                    return self.number(start, true);
+                }
+                let inner = self.unary()?;
+                self.emit(Instr::PushInt(NzInt::neg_one()), start..start + 1);
+                self.emit(Instr::Mul, start..inner.end);
+                Ok(start..inner.end)
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Range<usize>, ExprError> {
+        let start = self.pos_after_ws();
+        match self.peek() {
+            None => Err(self.error(start..start, ExprErrorKind::UnexpectedEnd)),
+            Some(b'(') => {
+                self.pos += 1;
+                self.expr()?;
+                match self.peek() {
+                    Some(b')') => {
+                        self.pos += 1;
+                        Ok(start..self.pos)
+                    }
+                    None => Err(self.error(self.pos..self.pos, ExprErrorKind::UnexpectedEnd)),
+                    Some(_) => Err(self.error(self.pos..self.pos + 1, ExprErrorKind::Syntax)),
+                }
+            }
+            Some(c) if c.is_ascii_digit() || c == b'.' => self.number(start, false),
+            Some(c) if c.is_ascii_alphabetic() || c == b'_' => {
+                let end = self.scan(start, |c| {
+                    c.is_ascii_alphanumeric() || c == b'_' || c == b'.'
+                });
+                self.pos = end;
+                let name = &self.src[start..end];
+                let value = self.vars.iter().find(|(n, _)| *n == name).map(|&(_, v)| v);
+                let value = value.ok_or(self.error(start..end, ExprErrorKind::UnknownVariable))?;
+                let instr = match value {
+                    NzValue::Int(x) => Instr::PushInt(x),
+                    NzValue::Float(x) => Instr::PushFloat(x),
+                    NzValue::Sign(s) => Instr::PushSign(s),
+                };
+                self.emit(instr, start..end);
+                Ok(start..end)
+            }
+            Some(_) => Err(self.error(start..start + 1, ExprErrorKind::Syntax)),
+        }
+    }
+
+    /// Numeric literal starting at self.pos; `start` includes a leading '-' if `neg`.
+    fn number(&mut self, start: usize, neg: bool) -> Result<Range<usize>, ExprError> {
+        let digits = self.pos;
+        let mut end = self.scan(digits, |c| c.is_ascii_digit() || c == b'.');
+        let mut float = self.src[digits..end].contains('.');
+        if matches!(self.src.as_bytes().get(end), Some(b'e' | b'E')) {
+            float = true;
+            end += 1;
+            if matches!(self.src.as_bytes().get(end), Some(b'+' | b'-')) {
+                end += 1;
+            }
+            end = self.scan(end, |c| c.is_ascii_digit());
+        }
+        self.pos = end;
+        // The sign is adjacent to the digits only when nothing separates them.
+        let text = if neg && start + 1 == digits {
+            &self.src[start..end]
+        } else {
+            &self.src[digits..end]
+        };
+        let negate = neg && start + 1 != digits;
+        let span = start..end;
+        let instr = if float {
+            let v: f64 = text
+                .parse()
+                .map_err(|_| self.error(span.clone(), ExprErrorKind::BadLiteral))?;
+            let v = if negate { -v } else { v };
+            Instr::PushFloat(
+                NzFloat::new(v).ok_or(self.error(span.clone(), ExprErrorKind::ZeroLiteral))?,
+            )
+        } else {
+            let v: i64 = text
+                .parse()
+                .map_err(|_| self.error(span.clone(), ExprErrorKind::BadLiteral))?;
+            let v = if negate {
+                v.checked_neg()
+                    .ok_or(self.error(span.clone(), ExprErrorKind::BadLiteral))?
+            } else {
+                v
+            };
+            Instr::PushInt(
+                NzInt::new(v).ok_or(self.error(span.clone(), ExprErrorKind::ZeroLiteral))?,
+            )
+        };
+        self.emit(instr, span.clone());
+        Ok(span)
+    }
+
+    /// End of the run of bytes from `from` that satisfy `f`.
+    #[inline]
+    fn scan(&self, from: usize, f: impl Fn(u8) -> bool) -> usize {
+        from + self.src.as_bytes()[from..]
+            .iter()
+            .take_while(|&&c| f(c))
+            .count()
+    }
+
+    #[inline]
+    fn skip_ws(&mut self) {
+        self.pos = self.scan(self.pos, |c| c.is_ascii_whitespace());
+    }
+
+    #[inline]
+    fn pos_after_ws(&mut self) -> usize {
+        self.skip_ws();
+        self.pos
+    }
+
+    /// Next non-whitespace byte, without consuming it.
+    #[inline]
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_ws();
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    #[inline]
+    fn emit(&mut self, instr: Instr, span: Range<usize>) {
+        self.code.push(instr);
+        self.spans.push(span);
+    }
+
+    #[inline]
+    fn error(&self, span: Range<usize>, kind: ExprErrorKind) -> ExprError {
+        ExprError { span, kind }
+    }
+}
@@ -0,0 +1,234 @@
+//! ffi: C ABI for the checked arithmetic (`capi` feature)
+//! Invariants:
+//! - Inputs arrive as plain int64_t/double and are validated here; C never hands Rust a zero NzInt
+//! - Out-parameters are written only when the status is NZ_STATUS_OK
+//!
+//! API:
+//! - nzint_new, nzint_checked_{add,sub,mul,div,rem}(a, b, out) -> NzStatus
+//! - nzfloat_new, nzfloat_checked_{add,sub,mul,div}(a, b, out) -> NzStatus
+//! - nz_status_message(status) -> static NUL-terminated description
+//!
+//! Design choices:
+//! - NzInt/NzFloat are repr(transparent), so `out` is an int64_t* / double* on the C side
+//! - One status enum covers NzError and NzfError; 0 is success, as C callers expect
+//! - Header: `cbindgen --config cbindgen.toml --crate nz-rs --output nz.h` (with `capi` enabled)
+//! - Linking: the embedding crate picks the artifact (staticlib/cdylib); this crate stays an rlib
+
+use core::ffi::{CStr, c_char};
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+
+/// Result code of every ffi function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NzStatus {
+    Ok = 0,
+    /// An argument was 0 (or NaN for floats).
+    InvalidInput = 1,
+    /// The out-pointer was null.
+    NullPointer = 2,
+    ZeroResult = 3,
+    DivOverflow = 4,
+    NotANumber = 5,
+    Infinite = 6,
+    OutOfRange = 7,
+    Overflow = 8,
+    Inexact = 9,
+    Domain = 10,
+    Underflow = 11,
+}
+
+impl From<NzError> for NzStatus {
+    fn from(e: NzError) -> Self {
+        match e {
+            NzError::ZeroResult => NzStatus::ZeroResult,
+            NzError::DivOverflow => NzStatus::DivOverflow,
+            NzError::OutOfRange => NzStatus::OutOfRange,
+            NzError::Overflow => NzStatus::Overflow,
+            NzError::Inexact => NzStatus::Inexact,
+            NzError::Domain => NzStatus::Domain,
+        }
+    }
+}
+
+impl From<NzfError> for NzStatus {
+    fn from(e: NzfError) -> Self {
+        match e {
+            NzfError::ZeroResult => NzStatus::ZeroResult,
+            NzfError::NotANumber => NzStatus::NotANumber,
+            NzfError::Infinite => NzStatus::Infinite,
+            NzfError::Underflow => NzStatus::Underflow,
+        }
+    }
+}
+
+/// Write `r` to `out` on success; shared tail of every exported function.
+#[inline]
+unsafe fn finish<T, E: Into<NzStatus>>(r: Result<T, E>, out: *mut T) -> NzStatus {
+    if out.is_null() {
+        return NzStatus::NullPointer;
+    }
+    match r {
+        Ok(v) => {
+            unsafe { out.write(v) };
+            NzStatus::Ok
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/* ----- NzInt ----- */
+
+/// Validate `v` into `*out`. InvalidInput if v == 0.
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_new(v: i64, out: *mut NzInt) -> NzStatus {
+    match NzInt::new(v) {
+        Some(x) => unsafe { finish(Ok::<_, NzError>(x), out) },
+        None => NzStatus::InvalidInput,
+    }
+}
+
+/// Validate both operands, apply `op`, and report through `out`.
+#[inline]
+unsafe fn int_op(
+    a: i64,
+    b: i64,
+    out: *mut NzInt,
+    op: fn(NzInt, NzInt) -> Result<NzInt, NzError>,
+) -> NzStatus {
+    match (NzInt::new(a), NzInt::new(b)) {
+        (Some(a), Some(b)) => unsafe { finish(op(a, b), out) },
+        _ => NzStatus::InvalidInput,
+    }
+}
+
+/// Sum of a and b (NzInt::checked_add).
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_checked_add(a: i64, b: i64, out: *mut NzInt) -> NzStatus {
+    unsafe { int_op(a, b, out, NzInt::checked_add) }
+}
+
+/// Difference a - b (NzInt::checked_sub).
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_checked_sub(a: i64, b: i64, out: *mut NzInt) -> NzStatus {
+    unsafe { int_op(a, b, out, NzInt::checked_sub) }
+}
+
+/// Product of a and b (NzInt::checked_mul).
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_checked_mul(a: i64, b: i64, out: *mut NzInt) -> NzStatus {
+    unsafe { int_op(a, b, out, NzInt::checked_mul) }
+}
+
+/// Truncating quotient a / b (NzInt::checked_div).
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_checked_div(a: i64, b: i64, out: *mut NzInt) -> NzStatus {
+    unsafe { int_op(a, b, out, NzInt::checked_div) }
+}
+
+/// Remainder of a / b (NzInt::checked_rem).
+/// # Safety
+/// `out` must be null or valid for writing one int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzint_checked_rem(a: i64, b: i64, out: *mut NzInt) -> NzStatus {
+    unsafe { int_op(a, b, out, NzInt::checked_rem) }
+}
+
+/* ----- NzFloat ----- */
+
+/// Validate `v` into `*out`. InvalidInput if v is ±0.0 or NaN.
+/// # Safety
+/// `out` must be null or valid for writing one double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzfloat_new(v: f64, out: *mut NzFloat) -> NzStatus {
+    match NzFloat::new(v) {
+        Some(x) => unsafe { finish(Ok::<_, NzfError>(x), out) },
+        None => NzStatus::InvalidInput,
+    }
+}
+
+/// Validate both operands, apply `op`, and report through `out`.
+#[inline]
+unsafe fn float_op(
+    a: f64,
+    b: f64,
+    out: *mut NzFloat,
+    op: fn(NzFloat, NzFloat) -> Result<NzFloat, NzfError>,
+) -> NzStatus {
+    match (NzFloat::new(a), NzFloat::new(b)) {
+        (Some(a), Some(b)) => unsafe { finish(op(a, b), out) },
+        _ => NzStatus::InvalidInput,
+    }
+}
+
+/// Sum of a and b (NzFloat::checked_add).
+/// # Safety
+/// `out` must be null or valid for writing one double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzfloat_checked_add(a: f64, b: f64, out: *mut NzFloat) -> NzStatus {
+    unsafe { float_op(a, b, out, NzFloat::checked_add) }
+}
+
+/// Difference a - b (NzFloat::checked_sub).
+/// # Safety
+/// `out` must be null or valid for writing one double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzfloat_checked_sub(a: f64, b: f64, out: *mut NzFloat) -> NzStatus {
+    unsafe { float_op(a, b, out, NzFloat::checked_sub) }
+}
+
+/// Product of a and b (NzFloat::checked_mul).
+/// # Safety
+/// `out` must be null or valid for writing one double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzfloat_checked_mul(a: f64, b: f64, out: *mut NzFloat) -> NzStatus {
+    unsafe { float_op(a, b, out, NzFloat::checked_mul) }
+}
+
+/// Quotient a / b (NzFloat::checked_div).
+/// # Safety
+/// `out` must be null or valid for writing one double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nzfloat_checked_div(a: f64, b: f64, out: *mut NzFloat) -> NzStatus {
+    unsafe { float_op(a, b, out, NzFloat::checked_div) }
+}
+
+/* ----- Status ----- */
+
+/// Messages indexed by NzStatus discriminant.
+const MESSAGES: [&CStr; 12] = [
+    c"ok",
+    c"argument is zero or NaN",
+    c"output pointer is null",
+    c"result would be zero",
+    c"integer division overflow",
+    c"result is NaN",
+    c"result is infinite",
+    c"value out of range",
+    c"result overflows",
+    c"result is inexact",
+    c"argument outside the domain",
+    c"result is subnormal",
+];
+
+/// Static, NUL-terminated description of a status code; never null, never freed.
+/// Takes int rather than NzStatus so that garbage from C cannot become an invalid enum.
+#[unsafe(no_mangle)]
+pub extern "C" fn nz_status_message(status: i32) -> *const c_char {
+    let s = usize::try_from(status)
+        .ok()
+        .and_then(|i| MESSAGES.get(i))
+        .copied();
+    s.unwrap_or(c"unknown status").as_ptr()
+}
@@ -0,0 +1,138 @@
+//! format: Zero-less (bijective) numerals for NzInt
+//! Invariants:
+//! - A bijective base-k numeral uses k digits worth 1..=k and no digit for zero, so every
+//!   positive integer has exactly one spelling and the empty numeral is 0
+//!
+//! API:
+//! - BijectiveBase::DECIMAL ("123456789A", A = ten), ::LETTERS ("A".."Z", spreadsheet columns),
+//!   BijectiveBase::new(digits) for custom ASCII digit sets
+//! - bijective(x, base) -> Bijective: lazy Display, '-' prefix for negatives
+//! - parse_bijective(s, base) -> Result<NzInt, NzParseError>
+//!
+//! Design choices:
+//! - Formatting writes into a stack buffer (no alloc), like NzInt::display_buffer
+//! - Parsing is exact-match on the digit set (no case folding); the empty string is
+//!   Err(Zero) because that is what it denotes, while "-" alone is Err(Invalid)
+
+use core::fmt;
+
+use crate::nzint::NzInt;
+use crate::nzparse::NzParseError;
+
+/// Digit set of a bijective numeral system: digits[i] has value i + 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BijectiveBase<'a> {
+    digits: &'a [u8],
+}
+
+impl<'a> BijectiveBase<'a> {
+    /// Bijective base ten: digits 1-9 and A for ten (10 is "A", 11 is "11", 20 is "1A").
+    pub const DECIMAL: BijectiveBase<'static> = BijectiveBase {
+        digits: b"123456789A",
+    };
+
+    /// Bijective base 26 with A..Z: 1 is "A", 26 is "Z", 27 is "AA" (spreadsheet columns).
+    pub const LETTERS: BijectiveBase<'static> = BijectiveBase {
+        digits: b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    };
+
+    /// Custom digit set, lowest value first. Returns None unless it has at least two
+    /// distinct ASCII characters and no '-'.
+    pub const fn new(digits: &'a str) -> Option<Self> {
+        let d = digits.as_bytes();
+        if d.len() < 2 {
+            return None;
+        }
+        let mut i = 0;
+        while i < d.len() {
+            if !d[i].is_ascii() || d[i] == b'-' {
+                return None;
+            }
+            let mut j = i + 1;
+            while j < d.len() {
+                if d[i] == d[j] {
+                    return None;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        Some(BijectiveBase { digits: d })
+    }
+
+    /// Number of digits k.
+    #[inline]
+    pub const fn radix(self) -> usize {
+        self.digits.len()
+    }
+
+    #[inline]
+    fn value_of(self, c: u8) -> Option<u64> {
+        self.digits
+            .iter()
+            .position(|&d| d == c)
+            .map(|i| i as u64 + 1)
+    }
+}
+
+/// Lazy bijective rendering of an NzInt; see [`bijective`].
+#[derive(Debug, Clone, Copy)]
+pub struct Bijective<'a> {
+    value: NzInt,
+    base: BijectiveBase<'a>,
+}
+
+/// Render `x` in the bijective numeral system `base`.
+#[inline]
+pub fn bijective(x: NzInt, base: BijectiveBase<'_>) -> Bijective<'_> {
+    Bijective { value: x, base }
+}
+
+impl fmt::Display for Bijective<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Base 2 is the longest: 64 digits for 2^63, plus the sign.
+        let mut buf = [0u8; 65];
+        let mut i = buf.len();
+        let k = self.base.radix() as u64;
+        let mut m = self.value.abs_unsigned().get();
+        while m > 0 {
+            // Digit values run 1..=k, so shift by one before the usual div/mod.
+            m -= 1;
+            i -= 1;
+            buf[i] = self.base.digits[(m % k) as usize];
+            m /= k;
+        }
+        if self.value.get() < 0 {
+            i -= 1;
+            buf[i] = b'-';
+        }
+        // BijectiveBase only holds ASCII digits.
+        f.pad(unsafe { core::str::from_utf8_unchecked(&buf[i..]) })
+    }
+}
+
+/// Parse a bijective numeral with an optional leading '-'.
+/// Err(Zero) for the empty string, Err(Invalid) for unknown characters or i64 overflow.
+pub fn parse_bijective(s: &str, base: BijectiveBase<'_>) -> Result<NzInt, NzParseError> {
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some("") => return Err(NzParseError::Invalid),
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let k = base.radix() as u64;
+    let mut m: u64 = 0;
+    for c in digits.bytes() {
+        let d = base.value_of(c).ok_or(NzParseError::Invalid)?;
+        m = m
+            .checked_mul(k)
+            .and_then(|m| m.checked_add(d))
+            .ok_or(NzParseError::Invalid)?;
+    }
+    let v = if neg {
+        // 2^63 is allowed here: it is |i64::MIN|.
+        0i64.checked_sub_unsigned(m)
+    } else {
+        i64::try_from(m).ok()
+    };
+    NzInt::new(v.ok_or(NzParseError::Invalid)?).ok_or(NzParseError::Zero)
+}
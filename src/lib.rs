@@ -0,0 +1,142 @@
+//! nz: Zero-free numeric types for the Non-zero VM (see README).
+//!
+//! Features:
+//! - (none): `#![no_std]`, core only
+//! - `alloc`: APIs returning Vec (e.g., NzInt::try_collect_report), the `vm` stack machine and its `expr` front-end
+//! - `std`: implies `alloc`; NzInterner, and float math through std
+//! - `libm`: float math (NzLog, NzFloat::checked_ln, ...) without std
+//! - `stable-hash`: Hash impls write the `to_stable_bytes()` encoding, identical across platforms
+//!   and releases
+//! - `strict-floats`: every checked float method (NzFloat, NzF32, NzComplex, batches, ...) fails
+//!   with NzfError::Infinite on a ±inf result
+//! - `deny-subnormals`: every checked float method fails with NzfError::Underflow on a subnormal
+//!   result
+//! - `sign-ops`: `Mul<nzSign>` for NzInt/NzFloat and `Mul<NzInt/NzFloat>` for nzSign
+//! - `bigint`: implies `alloc`; arbitrary-precision NzBigInt
+//! - `rand`: choose_weighted, NzUniform, StandardUniform for NzInt/NzFloat (plus NzWeightedIndex with `alloc`)
+//! - `half`: checked f16 conversions on NzF32/NzFloat
+//! - `num-traits`: One, Checked{Add,Sub,Mul,Div}, To/FromPrimitive for NzInt/NzFloat
+//! - `capi`: `extern "C"` functions in `ffi` (see cbindgen.toml for the header)
+//! - `wasm`: implies `std`; JavaScript classes NzInt/NzFloat via wasm-bindgen (module `wasm`)
+//! - `proptest` / `quickcheck`: imply `std`; Arbitrary for NzInt/NzFloat/nzSign, shrinking toward ±1
+//! - `sqlx` / `postgres`: imply `std`; NzInt/NzFloat as BIGINT/DOUBLE PRECISION columns (0 and NaN fail to decode)
+//! - `json` / `toml`: imply `alloc` / `std`; vm::NzValue to and from serde_json/toml values, and
+//!   lowering a config tree into expr variables (module `nzconfig`)
+//! - `serde` + `json`: test_support::roundtrip_all_formats, for tests of types with Nz fields
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod expr;
+#[cfg(feature = "capi")]
+pub mod ffi;
+pub mod format;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod nzarbitrary;
+#[cfg(feature = "bigint")]
+pub mod nzbigint;
+pub mod nzbounded;
+pub mod nzcomplex;
+#[cfg(any(feature = "json", feature = "toml"))]
+pub mod nzconfig;
+pub mod nzdecimal;
+pub mod nzdisplay;
+pub mod nzduration;
+pub mod nzf32;
+pub mod nzfloat;
+pub mod nzint;
+#[cfg(feature = "std")]
+pub mod nzintern;
+pub mod nzinterval;
+pub mod nziter;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod nzlog;
+pub mod nzmacro;
+pub mod nzmap;
+pub mod nzmat;
+#[cfg(feature = "num-traits")]
+mod nznumtraits;
+pub mod nzoption;
+pub mod nzoverflow;
+pub mod nzparse;
+pub mod nzpositive;
+#[cfg(feature = "rand")]
+pub mod nzrandom;
+pub mod nzrange;
+pub mod nzratio;
+#[cfg(feature = "serde")]
+mod nzserde;
+pub mod nzsign;
+#[cfg(feature = "alloc")]
+pub mod nzsignvec;
+#[cfg(any(feature = "sqlx", feature = "postgres"))]
+mod nzsql;
+pub mod nzstats;
+#[cfg(feature = "alloc")]
+pub mod nzvalidate;
+pub mod nzvec;
+pub mod nzwidth;
+pub mod poly;
+#[cfg(all(feature = "serde", feature = "json"))]
+pub mod test_support;
+#[cfg(feature = "alloc")]
+pub mod vm;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use error::Error;
+#[cfg(feature = "bigint")]
+pub use nzbigint::NzBigInt;
+pub use nzbounded::NzIntInRange;
+pub use nzcomplex::NzComplex;
+pub use nzdecimal::NzDecimal;
+pub use nzdisplay::{NzIntBuffer, SignStyle, SignedDisplay, format_signed};
+pub use nzduration::NzDuration;
+pub use nzf32::{NzF32, NzF64};
+pub use nzfloat::{NzFloat, NzFpCategory, NzfError, NzfParseError, Rounding};
+#[cfg(feature = "alloc")]
+pub use nzint::ZeroReport;
+pub use nzint::{NzError, NzInt};
+#[cfg(feature = "std")]
+pub use nzintern::{NzHandle, NzInterner};
+pub use nzinterval::{NzInterval, NzIntervalError};
+pub use nziter::{NzAccumulate, NzIteratorExt, NzStepError};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use nzlog::NzLog;
+pub use nzmap::NzResultExt;
+pub use nzmat::{NzMat2, NzMat3};
+pub use nzoption::NzOptionExt;
+pub use nzoverflow::{OverflowPolicy, Strict};
+pub use nzparse::NzParseError;
+pub use nzpositive::{NgFloat, NgInt, PzFloat, PzInt};
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub use nzrandom::NzWeightedIndex;
+#[cfg(feature = "rand")]
+pub use nzrandom::{NzUniform, NzUniformElem, choose_weighted};
+pub use nzrange::NzRange;
+pub use nzratio::NzRatio;
+pub use nzsign::{ApplySign, nzSign};
+#[cfg(feature = "alloc")]
+pub use nzsignvec::NzSignVec;
+#[cfg(feature = "alloc")]
+pub use nzstats::normalize;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use nzstats::softmax;
+pub use nzstats::{NonEmptySlice, NzProb, parallel_sum};
+#[cfg(feature = "alloc")]
+pub use nzvalidate::validate_chunked;
+pub use nzvec::{NzVec2, NzVec3};
+pub use nzwidth::{
+    NarrowsTo, NzI8, NzI16, NzI32, NzI64, NzI128, NzIsize, NzU8, NzU16, NzU32, NzU64, NzU128,
+    NzUsize, WidensTo,
+};
@@ -1,10 +1,4 @@
-// Ensure the nzint module exists and is declared
-mod nzint;
-mod nzfloat;
-mod nzsign;
-use crate::nzint::NzInt;
-use crate::nzfloat::NzFloat;
-use crate::nzsign::nzSign;
+use nz::{NzFloat, NzInt, nzSign};
 
 fn main() {
     let a = NzInt::new(3).unwrap();
@@ -0,0 +1,94 @@
+//! math: f64 functions that core does not provide
+//! Design choices:
+//! - With `std`, forward to the inherent f64 methods; otherwise use `libm`
+//! - Only compiled when one of the two is enabled; callers gate on the same condition
+
+macro_rules! math_fn {
+    ($($name:ident => $std:ident, $libm:ident;)+) => {$(
+        #[inline]
+        pub(crate) fn $name(x: f64) -> f64 {
+            #[cfg(feature = "std")]
+            { x.$std() }
+            #[cfg(not(feature = "std"))]
+            { libm::$libm(x) }
+        }
+    )+};
+}
+
+math_fn! {
+    sqrt => sqrt, sqrt;
+    ln => ln, log;
+    log10 => log10, log10;
+    exp => exp, exp;
+    ln_1p => ln_1p, log1p;
+    exp_m1 => exp_m1, expm1;
+    sin => sin, sin;
+    cos => cos, cos;
+    tan => tan, tan;
+    floor => floor, floor;
+    ceil => ceil, ceil;
+    trunc => trunc, trunc;
+    // rint rounds half to even under the default rounding mode
+    round_ties_even => round_ties_even, rint;
+}
+
+#[inline]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.powf(y)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::pow(x, y)
+    }
+}
+
+#[inline]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.powi(n)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::pow(x, n as f64)
+    }
+}
+
+#[inline]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.hypot(y)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::hypot(x, y)
+    }
+}
+
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        y.atan2(x)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::atan2(y, x)
+    }
+}
+
+// Fused x * y + z with a single rounding
+#[inline]
+pub(crate) fn mul_add(x: f64, y: f64, z: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.mul_add(y, z)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::fma(x, y, z)
+    }
+}
@@ -1,3 +0,0 @@
-mod nzint;
-mod nzfloat;
-mod nzsign;
@@ -0,0 +1,306 @@
+//! nzarbitrary: Property-testing generators (`proptest` / `quickcheck` features)
+//! Invariants:
+//! - Every generated and every shrunk value is valid: never 0, never NaN
+//!
+//! API:
+//! - proptest: Arbitrary for NzInt/NzFloat/nzSign, so `any::<NzInt>()` works; NzAny<T> is the strategy
+//! - quickcheck: Arbitrary for NzInt/NzFloat/nzSign
+//!
+//! Design choices:
+//! - Shrinking keeps the sign and walks the magnitude toward 1, the simplest non-zero value;
+//!   nzSign shrinks toward Pos
+//! - NzFloat walks the bit pattern of |x|, which is ordered like |x| itself, so 1e300 and
+//!   1e-300 both reach 1.0 in at most 64 halvings and ±inf shrinks to finite values
+//! - Generation is log-uniform in magnitude, so small and huge values are equally common
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+
+/// Types that shrink by moving an ordered magnitude key toward the key of ±1.
+trait Keyed: Copy {
+    const ONE: u64;
+    fn to_key(self) -> (u64, bool);
+    fn from_key(key: u64, negative: bool) -> Self;
+}
+
+impl Keyed for NzInt {
+    const ONE: u64 = 1;
+    #[inline]
+    fn to_key(self) -> (u64, bool) {
+        (self.abs_unsigned().get(), self.get() < 0)
+    }
+    #[inline]
+    fn from_key(key: u64, negative: bool) -> Self {
+        // key is in [1, 2^63]; 2^63 wraps onto i64::MIN, which only exists as negative.
+        let v = if negative {
+            (key as i64).wrapping_neg()
+        } else {
+            key as i64
+        };
+        unsafe { NzInt::new_unchecked(v) }
+    }
+}
+
+impl Keyed for NzFloat {
+    const ONE: u64 = 0x3FF0_0000_0000_0000; // 1.0f64.to_bits()
+    #[inline]
+    fn to_key(self) -> (u64, bool) {
+        let v = self.get();
+        (v.abs().to_bits(), v < 0.0)
+    }
+    #[inline]
+    fn from_key(key: u64, negative: bool) -> Self {
+        // Keys lie between |x| and 1.0: non-zero and at most +inf, never NaN.
+        let v = f64::from_bits(key);
+        unsafe { NzFloat::new_unchecked(if negative { -v } else { v }) }
+    }
+}
+
+/// Position of a value on its path toward ±1: `dist` key steps away from ONE.
+#[derive(Debug, Clone, Copy)]
+struct Path {
+    negative: bool,
+    above: bool,
+}
+
+impl Path {
+    fn of<T: Keyed>(x: T) -> (Path, u64) {
+        let (key, negative) = x.to_key();
+        (
+            Path {
+                negative,
+                above: key > T::ONE,
+            },
+            key.abs_diff(T::ONE),
+        )
+    }
+
+    fn at<T: Keyed>(self, dist: u64) -> T {
+        let key = if self.above {
+            T::ONE + dist
+        } else {
+            T::ONE - dist
+        };
+        T::from_key(key, self.negative)
+    }
+}
+
+/* ----- proptest ----- */
+
+#[cfg(feature = "proptest")]
+mod prop {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use proptest::arbitrary::Arbitrary;
+    use proptest::prelude::Rng;
+    use proptest::strategy::{Map, NewTree, Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    use super::{Keyed, Path};
+    use crate::nzfloat::NzFloat;
+    use crate::nzint::NzInt;
+    use crate::nzsign::nzSign;
+
+    /// Strategy behind `any::<NzInt>()` and `any::<NzFloat>()`.
+    pub struct NzAny<T>(PhantomData<T>);
+
+    impl<T> Clone for NzAny<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for NzAny<T> {}
+
+    impl<T> fmt::Debug for NzAny<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "NzAny<{}>", core::any::type_name::<T>())
+        }
+    }
+
+    /// Value tree that binary-searches the distance to ±1 (proptest's integer shrinking,
+    /// re-targeted from 0 to ±1).
+    #[derive(Debug, Clone, Copy)]
+    pub struct NzValueTree<T> {
+        path: Path,
+        lo: u64,
+        curr: u64,
+        hi: u64,
+        _t: PhantomData<T>,
+    }
+
+    impl<T> NzValueTree<T> {
+        fn reposition(&mut self) -> bool {
+            let mid = self.lo + (self.hi - self.lo) / 2;
+            if mid == self.curr {
+                false
+            } else {
+                self.curr = mid;
+                true
+            }
+        }
+    }
+
+    impl<T: Keyed + fmt::Debug> ValueTree for NzValueTree<T> {
+        type Value = T;
+
+        fn current(&self) -> T {
+            self.path.at(self.curr)
+        }
+
+        fn simplify(&mut self) -> bool {
+            if self.hi <= self.lo {
+                return false;
+            }
+            self.hi = self.curr;
+            self.reposition()
+        }
+
+        fn complicate(&mut self) -> bool {
+            if self.hi <= self.lo {
+                return false;
+            }
+            self.lo = self.curr + 1;
+            self.reposition()
+        }
+    }
+
+    /// Sampling half of NzAny.
+    trait Generate: Keyed {
+        fn generate(rng: &mut impl Rng) -> Self;
+    }
+
+    impl Generate for NzInt {
+        fn generate(rng: &mut impl Rng) -> Self {
+            loop {
+                // Random bits shifted by a random width: every magnitude scale is equally likely.
+                let v = (rng.next_u64() as i64) >> (rng.next_u32() % 64);
+                if let Some(x) = NzInt::new(v) {
+                    return x;
+                }
+            }
+        }
+    }
+
+    impl Generate for NzFloat {
+        fn generate(rng: &mut impl Rng) -> Self {
+            loop {
+                // Random bit patterns cover every exponent (subnormals and ±inf included).
+                if let Some(x) = NzFloat::new(f64::from_bits(rng.next_u64())) {
+                    return x;
+                }
+            }
+        }
+    }
+
+    impl<T: Generate + fmt::Debug> Strategy for NzAny<T> {
+        type Tree = NzValueTree<T>;
+        type Value = T;
+
+        fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+            let (path, dist) = Path::of(T::generate(runner.rng()));
+            Ok(NzValueTree {
+                path,
+                lo: 0,
+                curr: dist,
+                hi: dist,
+                _t: PhantomData,
+            })
+        }
+    }
+
+    impl Arbitrary for NzInt {
+        type Parameters = ();
+        type Strategy = NzAny<NzInt>;
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            NzAny(PhantomData)
+        }
+    }
+
+    impl Arbitrary for NzFloat {
+        type Parameters = ();
+        type Strategy = NzAny<NzFloat>;
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            NzAny(PhantomData)
+        }
+    }
+
+    impl Arbitrary for nzSign {
+        type Parameters = ();
+        type Strategy = Map<proptest::bool::Any, fn(bool) -> nzSign>;
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            // bool shrinks toward false, which maps to Pos.
+            proptest::bool::ANY.prop_map(|neg| if neg { nzSign::Neg } else { nzSign::Pos })
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub use prop::{NzAny, NzValueTree};
+
+/* ----- quickcheck ----- */
+
+#[cfg(feature = "quickcheck")]
+mod qc {
+    use std::boxed::Box;
+
+    use quickcheck::{Arbitrary, Gen};
+
+    use super::{Keyed, Path};
+    use crate::nzfloat::NzFloat;
+    use crate::nzint::NzInt;
+    use crate::nzsign::nzSign;
+
+    /// ±1 first, then candidates closing in on `x` (quickcheck's halving order).
+    fn shrink_toward_one<T: Keyed + 'static>(x: T) -> Box<dyn Iterator<Item = T>> {
+        let (path, dist) = Path::of(x);
+        Box::new(
+            (0..64)
+                .map(move |k| dist >> k)
+                .take_while(|&step| step > 0)
+                .map(move |step| path.at(dist - step)),
+        )
+    }
+
+    impl Arbitrary for NzInt {
+        fn arbitrary(g: &mut Gen) -> Self {
+            loop {
+                if let Some(x) = NzInt::new(i64::arbitrary(g)) {
+                    return x;
+                }
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            shrink_toward_one(*self)
+        }
+    }
+
+    impl Arbitrary for NzFloat {
+        fn arbitrary(g: &mut Gen) -> Self {
+            loop {
+                if let Some(x) = NzFloat::new(f64::arbitrary(g)) {
+                    return x;
+                }
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            shrink_toward_one(*self)
+        }
+    }
+
+    impl Arbitrary for nzSign {
+        fn arbitrary(g: &mut Gen) -> Self {
+            nzSign::from_bool(bool::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                nzSign::Neg => quickcheck::single_shrinker(nzSign::Pos),
+                nzSign::Pos => quickcheck::empty_shrinker(),
+            }
+        }
+    }
+}
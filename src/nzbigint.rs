@@ -0,0 +1,152 @@
+//! nzbigint: Non-zero arbitrary-precision integer (`bigint` feature)
+//! Invariants:
+//! - Value is never zero, exactly as for nzint
+//!
+//! API:
+//! - NzBigInt::new(BigInt) -> Option<Self>, get(), into_inner()
+//! - checked_add/sub/mul/div/rem: Err(ZeroResult) is the only failure (no overflow)
+//! - pow(), gcd(), modpow() with a non-zero modulus
+//! - From<NzInt>, TryFrom<&NzBigInt> for NzInt (Err(OutOfRange) if it does not fit)
+//!
+//! Design choices:
+//! - Wraps num_bigint::BigInt; operations take &self since values are heap-backed
+
+use core::fmt;
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::Zero;
+
+use crate::nzint::{NzError, NzInt};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NzBigInt(BigInt);
+
+impl NzBigInt {
+    /// Create from BigInt. Returns None if v == 0.
+    #[inline]
+    pub fn new(v: BigInt) -> Option<Self> {
+        if v.is_zero() { None } else { Some(NzBigInt(v)) }
+    }
+
+    /// Borrow the inner BigInt.
+    #[inline]
+    pub fn get(&self) -> &BigInt {
+        &self.0
+    }
+
+    /// Unwrap into the inner BigInt.
+    #[inline]
+    pub fn into_inner(self) -> BigInt {
+        self.0
+    }
+
+    /// Checked addition. Returns Err(ZeroResult) if the sum is zero.
+    #[inline]
+    pub fn checked_add(&self, rhs: &NzBigInt) -> Result<NzBigInt, NzError> {
+        Self::check(&self.0 + &rhs.0)
+    }
+
+    /// Checked subtraction. Returns Err(ZeroResult) if the difference is zero.
+    #[inline]
+    pub fn checked_sub(&self, rhs: &NzBigInt) -> Result<NzBigInt, NzError> {
+        Self::check(&self.0 - &rhs.0)
+    }
+
+    /// Checked multiplication. Never fails (no overflow); kept for parity with NzInt.
+    #[inline]
+    pub fn checked_mul(&self, rhs: &NzBigInt) -> Result<NzBigInt, NzError> {
+        Self::check(&self.0 * &rhs.0)
+    }
+
+    /// Checked division (truncates toward zero). Returns Err(ZeroResult) if |self| < |rhs|.
+    #[inline]
+    pub fn checked_div(&self, rhs: &NzBigInt) -> Result<NzBigInt, NzError> {
+        Self::check(&self.0 / &rhs.0)
+    }
+
+    /// Checked remainder (sign follows self). Returns Err(ZeroResult) if rhs divides self.
+    #[inline]
+    pub fn checked_rem(&self, rhs: &NzBigInt) -> Result<NzBigInt, NzError> {
+        Self::check(&self.0 % &rhs.0)
+    }
+
+    /// Raise to a power. Total: a non-zero base never yields zero.
+    #[inline]
+    pub fn pow(&self, exp: u32) -> NzBigInt {
+        NzBigInt(self.0.pow(exp))
+    }
+
+    /// Greatest common divisor (always positive). Total: gcd(a, b) >= 1 when a != 0.
+    #[inline]
+    pub fn gcd(&self, rhs: &NzBigInt) -> NzBigInt {
+        NzBigInt(self.0.gcd(&rhs.0))
+    }
+
+    /// (self ^ exp) mod modulus, rounding like mod_floor: the result has the sign of modulus.
+    /// Returns Err(ZeroResult) if modulus divides self ^ exp.
+    #[inline]
+    pub fn modpow(&self, exp: &BigUint, modulus: &NzBigInt) -> Result<NzBigInt, NzError> {
+        let exp = BigInt::from_biguint(Sign::Plus, exp.clone());
+        Self::check(self.0.modpow(&exp, &modulus.0))
+    }
+
+    #[inline]
+    fn check(r: BigInt) -> Result<NzBigInt, NzError> {
+        NzBigInt::new(r).ok_or(NzError::ZeroResult)
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Debug for NzBigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NzBigInt").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for NzBigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<NzInt> for NzBigInt {
+    #[inline]
+    fn from(v: NzInt) -> NzBigInt {
+        NzBigInt(BigInt::from(v.get()))
+    }
+}
+
+impl TryFrom<&NzBigInt> for NzInt {
+    type Error = NzError;
+    #[inline]
+    fn try_from(v: &NzBigInt) -> Result<NzInt, NzError> {
+        let r = i64::try_from(&v.0).map_err(|_| NzError::OutOfRange)?;
+        // The value is preserved, so it is still non-zero.
+        Ok(unsafe { NzInt::new_unchecked(r) })
+    }
+}
+
+impl TryFrom<NzBigInt> for NzInt {
+    type Error = NzError;
+    #[inline]
+    fn try_from(v: NzBigInt) -> Result<NzInt, NzError> {
+        NzInt::try_from(&v)
+    }
+}
+
+impl TryFrom<BigInt> for NzBigInt {
+    type Error = NzError;
+    #[inline]
+    fn try_from(v: BigInt) -> Result<Self, Self::Error> {
+        NzBigInt::new(v).ok_or(NzError::ZeroResult)
+    }
+}
+
+impl From<NzBigInt> for BigInt {
+    #[inline]
+    fn from(v: NzBigInt) -> BigInt {
+        v.0
+    }
+}
@@ -0,0 +1,125 @@
+//! nzbounded: Non-zero integer restricted to [MIN, MAX]
+//! Invariants:
+//! - Value is non-zero and MIN <= value <= MAX
+//! - MIN <= MAX and the range holds at least one non-zero value (checked at compile time)
+//!
+//! API:
+//! - NzIntInRange::<MIN, MAX>::new(v) -> Result<Self, NzError>
+//! - checked_add/sub/mul/div: exact arithmetic, Err(ZeroResult) on zero, Err(OutOfRange) outside bounds
+//! - From<NzIntInRange> for NzInt, TryFrom<NzInt>
+
+use core::fmt;
+
+use crate::nzint::{NzError, NzInt};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NzIntInRange<const MIN: i64, const MAX: i64>(NzInt);
+
+impl<const MIN: i64, const MAX: i64> NzIntInRange<MIN, MAX> {
+    /// Rejects empty ranges and [0, 0] at compile time.
+    const VALID: () = assert!(
+        MIN <= MAX && !(MIN == 0 && MAX == 0),
+        "NzIntInRange: empty range"
+    );
+
+    /// Create from i64. Returns Err(ZeroResult) for 0 and Err(OutOfRange) outside [MIN, MAX].
+    #[inline]
+    pub const fn new(v: i64) -> Result<Self, NzError> {
+        let () = Self::VALID;
+        match NzInt::new(v) {
+            None => Err(NzError::ZeroResult),
+            Some(_) if v < MIN || v > MAX => Err(NzError::OutOfRange),
+            Some(nz) => Ok(NzIntInRange(nz)),
+        }
+    }
+
+    /// Get the inner i64.
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.0.get()
+    }
+
+    /// Get as an unbounded NzInt.
+    #[inline]
+    pub const fn to_nzint(self) -> NzInt {
+        self.0
+    }
+
+    /// Checked addition (no wrapping). Err(ZeroResult) if zero, Err(OutOfRange) outside bounds.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Result<Self, NzError> {
+        Self::new(
+            self.get()
+                .checked_add(rhs.get())
+                .ok_or(NzError::OutOfRange)?,
+        )
+    }
+
+    /// Checked subtraction (no wrapping). Err(ZeroResult) if zero, Err(OutOfRange) outside bounds.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, NzError> {
+        Self::new(
+            self.get()
+                .checked_sub(rhs.get())
+                .ok_or(NzError::OutOfRange)?,
+        )
+    }
+
+    /// Checked multiplication (no wrapping). Err(OutOfRange) outside bounds.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, NzError> {
+        Self::new(
+            self.get()
+                .checked_mul(rhs.get())
+                .ok_or(NzError::OutOfRange)?,
+        )
+    }
+
+    /// Checked division (truncates toward zero). Err(ZeroResult) if the quotient is zero,
+    /// Err(OutOfRange) outside bounds (including i64::MIN / -1).
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Result<Self, NzError> {
+        Self::new(
+            self.get()
+                .checked_div(rhs.get())
+                .ok_or(NzError::OutOfRange)?,
+        )
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl<const MIN: i64, const MAX: i64> fmt::Debug for NzIntInRange<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NzIntInRange").field(&self.get()).finish()
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> fmt::Display for NzIntInRange<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get())
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<NzIntInRange<MIN, MAX>> for NzInt {
+    #[inline]
+    fn from(v: NzIntInRange<MIN, MAX>) -> NzInt {
+        v.0
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<NzInt> for NzIntInRange<MIN, MAX> {
+    type Error = NzError;
+    #[inline]
+    fn try_from(v: NzInt) -> Result<Self, Self::Error> {
+        Self::new(v.get())
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<i64> for NzIntInRange<MIN, MAX> {
+    type Error = NzError;
+    #[inline]
+    fn try_from(v: i64) -> Result<Self, Self::Error> {
+        Self::new(v)
+    }
+}
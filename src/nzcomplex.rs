@@ -0,0 +1,181 @@
+//! nzcomplex: Non-zero complex number over f64
+//! Invariants:
+//! - The value is never 0+0i; one component may be ±0.0, but not both
+//! - Neither component is NaN
+//!
+//! API:
+//! - NzComplex::new(re, im) -> Option<Self>, re(), im(), conj()
+//! - checked_add/sub/mul/div -> Result<Self, NzfError> (same error contract as NzFloat)
+//! - norm(), arg(), to_polar(), from_polar() (need the `std` or `libm` feature)
+//! - From<NzFloat> (purely real value)
+//!
+//! Design choices:
+//! - Components are plain f64 so axis values (5+0i, 0-2i) are representable
+//! - Division uses Smith's algorithm to avoid spurious overflow in c^2 + d^2
+//! - new() checks the invariant only; the `strict-floats` / `deny-subnormals` rules apply to
+//!   checked_* results, with "subnormal" meaning |z| < f64::MIN_POSITIVE
+
+use core::fmt;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::nzfloat::{NzFloat, NzfError};
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct NzComplex {
+    re: f64,
+    im: f64,
+}
+
+impl NzComplex {
+    /// Create re + im·i; rejects 0+0i and NaN components. Like NzFloat::new, infinite and
+    /// subnormal components are accepted whatever the features.
+    #[inline]
+    pub fn new(re: f64, im: f64) -> Option<Self> {
+        if re.is_nan() || im.is_nan() || (re == 0.0 && im == 0.0) {
+            return None;
+        }
+        Some(NzComplex { re, im })
+    }
+
+    /// Real part (may be ±0.0).
+    #[inline]
+    pub fn re(self) -> f64 {
+        self.re
+    }
+
+    /// Imaginary part (may be ±0.0).
+    #[inline]
+    pub fn im(self) -> f64 {
+        self.im
+    }
+
+    /// Complex conjugate; total.
+    #[inline]
+    pub fn conj(self) -> NzComplex {
+        NzComplex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Checked addition. Err(ZeroResult) if both components cancel.
+    #[inline]
+    pub fn checked_add(self, rhs: NzComplex) -> Result<NzComplex, NzfError> {
+        Self::check(self.re + rhs.re, self.im + rhs.im)
+    }
+
+    /// Checked subtraction. Err(ZeroResult) if self == rhs.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzComplex) -> Result<NzComplex, NzfError> {
+        Self::check(self.re - rhs.re, self.im - rhs.im)
+    }
+
+    /// Checked multiplication. Err(ZeroResult) only on underflow.
+    #[inline]
+    pub fn checked_mul(self, rhs: NzComplex) -> Result<NzComplex, NzfError> {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        Self::check(a * c - b * d, a * d + b * c)
+    }
+
+    /// Checked division. The divisor is non-zero by invariant; Err(ZeroResult) only on underflow.
+    #[inline]
+    pub fn checked_div(self, rhs: NzComplex) -> Result<NzComplex, NzfError> {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        // Smith's algorithm: scale by the larger divisor component.
+        let (re, im) = if c.abs() >= d.abs() {
+            let r = d / c;
+            let den = c + d * r;
+            ((a + b * r) / den, (b - a * r) / den)
+        } else {
+            let r = c / d;
+            let den = c * r + d;
+            ((a * r + b) / den, (b * r - a) / den)
+        };
+        Self::check(re, im)
+    }
+
+    /// Magnitude |z|. Total: at least one component is non-zero, and hypot never underflows below it.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn norm(self) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(math::hypot(self.re, self.im)) }
+    }
+
+    /// Argument (phase angle) in [-π, π]. Not an NzFloat: positive reals have arg 0.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn arg(self) -> f64 {
+        math::atan2(self.im, self.re)
+    }
+
+    /// Polar form (|z|, arg z).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn to_polar(self) -> (NzFloat, f64) {
+        (self.norm(), self.arg())
+    }
+
+    /// Build r·e^(iθ). Err(ZeroResult) if both components underflow; Err(NotANumber) for
+    /// a non-finite theta.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn from_polar(r: NzFloat, theta: f64) -> Result<NzComplex, NzfError> {
+        Self::check(r.get() * math::cos(theta), r.get() * math::sin(theta))
+    }
+
+    /// Validate an arithmetic result with the checked-op rules, applied to the value as a whole:
+    /// zero and subnormal describe |z| (so 1+1e-310i is fine), NaN and ±inf either component.
+    #[inline]
+    fn check(re: f64, im: f64) -> Result<NzComplex, NzfError> {
+        match NzfError::classify(
+            re.is_nan() || im.is_nan(),
+            re == 0.0 && im == 0.0,
+            re.is_infinite() || im.is_infinite(),
+            magnitude_is_subnormal(re, im),
+        ) {
+            None => Ok(NzComplex { re, im }),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+/// Whether |re + im·i| is below f64::MIN_POSITIVE. Scaling by 2^600 is exact and lifts the
+/// squares of subnormal components into the normal range, so no hypot is needed.
+#[inline]
+fn magnitude_is_subnormal(re: f64, im: f64) -> bool {
+    const SCALE: f64 = f64::from_bits((1023 + 600) << 52);
+    if re.abs() >= f64::MIN_POSITIVE || im.abs() >= f64::MIN_POSITIVE {
+        return false;
+    }
+    let (x, y, limit) = (re * SCALE, im * SCALE, f64::MIN_POSITIVE * SCALE);
+    x * x + y * y < limit * limit
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Debug for NzComplex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NzComplex")
+            .field("re", &self.re)
+            .field("im", &self.im)
+            .finish()
+    }
+}
+
+impl fmt::Display for NzComplex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = if self.im.is_sign_negative() { '-' } else { '+' };
+        write!(f, "{}{}{}i", self.re, op, self.im.abs())
+    }
+}
+
+impl From<NzFloat> for NzComplex {
+    #[inline]
+    fn from(v: NzFloat) -> NzComplex {
+        NzComplex {
+            re: v.get(),
+            im: 0.0,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! nzconfig: vm::NzValue from and to config trees (features `json`, `toml`)
+//! Mapping:
+//! - integer <-> Int, float <-> Float, bool <-> Sign (true is Pos)
+//! - 0, ±0.0 and NaN are rejected (Zero / NotANumber); so are integers outside i64 and every
+//!   other node kind (Invalid)
+//!
+//! API:
+//! - TryFrom<&Value> / TryFrom<Value> for NzValue, and From<NzValue> for Value, for both
+//!   serde_json::Value and toml::Value
+//! - lower_json(&tree) / lower_toml(&tree) -> Result<Vec<(String, NzValue)>, ConfigError>:
+//!   every leaf, named by its path, ready to be passed to expr::eval_with
+//! - ConfigError { path, error }: the path of the offending node and the NzParseError
+//!
+//! Design choices:
+//! - A path joins object keys and array indices with '.' ("servers.0.weight"; "" is the root),
+//!   which expr accepts as a variable name
+//! - Lowering stops at the first bad leaf, visiting entries in the map's own order
+//! - JSON has no infinities: an infinite Float becomes null, as serde_json's From<f64> does
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzparse::NzParseError;
+use crate::nzsign::nzSign;
+use crate::vm::NzValue;
+
+/// A config node that does not lower to an NzValue, and where it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    /// Dotted path of the node ("" for the root).
+    pub path: String,
+    pub error: NzParseError,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            self.error.fmt(f)
+        } else {
+            write!(f, "{}: {}", self.path, self.error)
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+fn int(v: i64) -> Result<NzValue, NzParseError> {
+    NzInt::new(v).map(NzValue::Int).ok_or(NzParseError::Zero)
+}
+
+fn float(v: f64) -> Result<NzValue, NzParseError> {
+    if v.is_nan() {
+        return Err(NzParseError::NotANumber);
+    }
+    NzFloat::new(v)
+        .map(NzValue::Float)
+        .ok_or(NzParseError::Zero)
+}
+
+fn root(error: NzParseError) -> ConfigError {
+    ConfigError {
+        path: String::new(),
+        error,
+    }
+}
+
+/// One step down a config tree: an object key or an array index.
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+impl fmt::Display for Segment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Key(k) => f.write_str(k),
+            Segment::Index(i) => i.fmt(f),
+        }
+    }
+}
+
+type Children<'a, V> = Box<dyn Iterator<Item = (Segment<'a>, &'a V)> + 'a>;
+
+/// A config tree node, as seen by lower_*: a leaf value or the node's children.
+trait ConfigNode: Sized {
+    fn leaf(&self) -> Result<NzValue, NzParseError>;
+    fn children(&self) -> Option<Children<'_, Self>>;
+}
+
+fn walk<V: ConfigNode>(
+    v: &V,
+    path: &mut String,
+    out: &mut Vec<(String, NzValue)>,
+) -> Result<(), ConfigError> {
+    let Some(children) = v.children() else {
+        let x = v.leaf().map_err(|error| ConfigError {
+            path: path.clone(),
+            error,
+        })?;
+        out.push((path.clone(), x));
+        return Ok(());
+    };
+    for (segment, child) in children {
+        let len = path.len();
+        if len != 0 {
+            path.push('.');
+        }
+        let _ = write!(path, "{}", segment);
+        walk(child, path, out)?;
+        path.truncate(len);
+    }
+    Ok(())
+}
+
+fn lower<V: ConfigNode>(tree: &V) -> Result<Vec<(String, NzValue)>, ConfigError> {
+    let mut out = Vec::new();
+    walk(tree, &mut String::new(), &mut out)?;
+    Ok(out)
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use serde_json::Value;
+
+    impl ConfigNode for Value {
+        fn leaf(&self) -> Result<NzValue, NzParseError> {
+            match self {
+                Value::Number(n) => match n.as_i64() {
+                    Some(i) => int(i),
+                    None if n.is_u64() => Err(NzParseError::Invalid),
+                    None => float(n.as_f64().ok_or(NzParseError::Invalid)?),
+                },
+                Value::Bool(b) => Ok(NzValue::Sign(nzSign::from_bool(*b))),
+                _ => Err(NzParseError::Invalid),
+            }
+        }
+
+        fn children(&self) -> Option<Children<'_, Self>> {
+            match self {
+                Value::Object(map) => Some(Box::new(map.iter().map(|(k, v)| (Segment::Key(k), v)))),
+                Value::Array(items) => Some(Box::new(
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| (Segment::Index(i), v)),
+                )),
+                _ => None,
+            }
+        }
+    }
+
+    /// Every leaf of `tree` as a (path, value) pair, in document order.
+    #[inline]
+    pub fn lower_json(tree: &Value) -> Result<Vec<(String, NzValue)>, ConfigError> {
+        lower(tree)
+    }
+
+    impl TryFrom<&Value> for NzValue {
+        type Error = ConfigError;
+        #[inline]
+        fn try_from(v: &Value) -> Result<Self, Self::Error> {
+            v.leaf().map_err(root)
+        }
+    }
+
+    impl TryFrom<Value> for NzValue {
+        type Error = ConfigError;
+        #[inline]
+        fn try_from(v: Value) -> Result<Self, Self::Error> {
+            NzValue::try_from(&v)
+        }
+    }
+
+    impl From<NzValue> for Value {
+        fn from(v: NzValue) -> Self {
+            match v {
+                NzValue::Int(x) => Value::from(x.get()),
+                NzValue::Float(x) => Value::from(x.get()),
+                NzValue::Sign(s) => Value::Bool(s.to_bool()),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+pub use json::lower_json;
+
+#[cfg(feature = "toml")]
+mod toml {
+    use super::*;
+    use ::toml::Value;
+
+    impl ConfigNode for Value {
+        fn leaf(&self) -> Result<NzValue, NzParseError> {
+            match self {
+                Value::Integer(i) => int(*i),
+                Value::Float(x) => float(*x),
+                Value::Boolean(b) => Ok(NzValue::Sign(nzSign::from_bool(*b))),
+                _ => Err(NzParseError::Invalid),
+            }
+        }
+
+        fn children(&self) -> Option<Children<'_, Self>> {
+            match self {
+                Value::Table(table) => {
+                    Some(Box::new(table.iter().map(|(k, v)| (Segment::Key(k), v))))
+                }
+                Value::Array(items) => Some(Box::new(
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| (Segment::Index(i), v)),
+                )),
+                _ => None,
+            }
+        }
+    }
+
+    /// Every leaf of `tree` as a (path, value) pair, in document order.
+    #[inline]
+    pub fn lower_toml(tree: &Value) -> Result<Vec<(String, NzValue)>, ConfigError> {
+        lower(tree)
+    }
+
+    impl TryFrom<&Value> for NzValue {
+        type Error = ConfigError;
+        #[inline]
+        fn try_from(v: &Value) -> Result<Self, Self::Error> {
+            v.leaf().map_err(root)
+        }
+    }
+
+    impl TryFrom<Value> for NzValue {
+        type Error = ConfigError;
+        #[inline]
+        fn try_from(v: Value) -> Result<Self, Self::Error> {
+            NzValue::try_from(&v)
+        }
+    }
+
+    impl From<NzValue> for Value {
+        fn from(v: NzValue) -> Self {
+            match v {
+                NzValue::Int(x) => Value::Integer(x.get()),
+                NzValue::Float(x) => Value::Float(x.get()),
+                NzValue::Sign(s) => Value::Boolean(s.to_bool()),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+pub use self::toml::lower_toml;
@@ -0,0 +1,355 @@
+//! nzdecimal: Non-zero fixed-point decimal (mantissa × 10^-scale)
+//! Invariants:
+//! - mantissa is an NzInt, so the value is never zero
+//! - scale <= NzDecimal::MAX_SCALE (18), so 10^scale fits in i64
+//!
+//! API:
+//! - NzDecimal::new(mantissa, scale) -> Option<Self>, mantissa(), scale()
+//! - checked_add/sub/mul: exact; Err(Overflow) if the result does not fit, Err(ZeroResult) if 0
+//! - checked_div(rhs, scale, Rounding) and rescale(scale, Rounding): rounded to the given scale;
+//!   Err(ZeroResult) if the rounded result is 0
+//! - FromStr ("-12.50" keeps scale 2), Display (prints all `scale` digits), to_float()
+//! - from_float(x, scale, Rounding) rounds the float's exact binary value; from_float_exact
+//!   returns Err(Inexact) instead of rounding
+//! - Eq/Ord/Hash are numeric: 1.5 == 1.50
+//!
+//! Design choices:
+//! - Overflow is an error, never a wrap: unlike NzInt::checked_*, a wrapped price is a wrong price
+//! - Intermediates use i128, so aligning scales or multiplying never overflows before the final fit check
+//! - Rounding reuses NzFloat's Rounding enum (Floor/Ceil/Trunc/Nearest, ties to even)
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::nzfloat::{NzFloat, Rounding};
+use crate::nzint::{NzError, NzInt};
+use crate::nzparse::NzParseError;
+
+#[derive(Clone, Copy)]
+pub struct NzDecimal {
+    mantissa: NzInt,
+    scale: u8,
+}
+
+/// 10^e as i128; e <= 36 always fits.
+#[inline]
+const fn pow10(e: u32) -> i128 {
+    10i128.pow(e)
+}
+
+/// n / d rounded by `mode`; d != 0.
+fn div_round(n: i128, d: i128, mode: Rounding) -> i128 {
+    let (q, r) = (n / d, n % d);
+    if r == 0 {
+        return q;
+    }
+    // Direction away from zero for the true quotient.
+    let away = if (n < 0) != (d < 0) { -1 } else { 1 };
+    match mode {
+        Rounding::Trunc => q,
+        Rounding::Floor => {
+            if away < 0 {
+                q - 1
+            } else {
+                q
+            }
+        }
+        Rounding::Ceil => {
+            if away > 0 {
+                q + 1
+            } else {
+                q
+            }
+        }
+        Rounding::Nearest => match (2 * r.unsigned_abs()).cmp(&d.unsigned_abs()) {
+            Ordering::Less => q,
+            Ordering::Greater => q + away,
+            Ordering::Equal => {
+                if q % 2 == 0 {
+                    q
+                } else {
+                    q + away
+                }
+            }
+        },
+    }
+}
+
+impl NzDecimal {
+    /// Largest supported scale (digits after the point).
+    pub const MAX_SCALE: u8 = 18;
+
+    /// mantissa × 10^-scale. Returns None if scale > MAX_SCALE.
+    #[inline]
+    pub const fn new(mantissa: NzInt, scale: u8) -> Option<Self> {
+        if scale <= Self::MAX_SCALE {
+            Some(NzDecimal { mantissa, scale })
+        } else {
+            None
+        }
+    }
+
+    /// Unscaled integer value.
+    #[inline]
+    pub const fn mantissa(self) -> NzInt {
+        self.mantissa
+    }
+
+    /// Number of digits after the decimal point.
+    #[inline]
+    pub const fn scale(self) -> u8 {
+        self.scale
+    }
+
+    /// Validate an i128 mantissa at the given scale.
+    #[inline]
+    fn from_wide(m: i128, scale: u8) -> Result<NzDecimal, NzError> {
+        let m = i64::try_from(m).map_err(|_| NzError::Overflow)?;
+        let mantissa = NzInt::new(m).ok_or(NzError::ZeroResult)?;
+        Ok(NzDecimal { mantissa, scale })
+    }
+
+    /// Both mantissas at the larger scale (exact in i128).
+    #[inline]
+    fn aligned(self, rhs: NzDecimal) -> (i128, i128, u8) {
+        let s = self.scale.max(rhs.scale);
+        let a = self.mantissa.get() as i128 * pow10((s - self.scale) as u32);
+        let b = rhs.mantissa.get() as i128 * pow10((s - rhs.scale) as u32);
+        (a, b, s)
+    }
+
+    /// Exact sum at the larger scale.
+    #[inline]
+    pub fn checked_add(self, rhs: NzDecimal) -> Result<NzDecimal, NzError> {
+        let (a, b, s) = self.aligned(rhs);
+        Self::from_wide(a + b, s)
+    }
+
+    /// Exact difference at the larger scale.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzDecimal) -> Result<NzDecimal, NzError> {
+        let (a, b, s) = self.aligned(rhs);
+        Self::from_wide(a - b, s)
+    }
+
+    /// Exact product; the scales add. Err(Overflow) if the mantissa or the scale
+    /// (above MAX_SCALE) does not fit; rescale an operand first to trade digits for range.
+    #[inline]
+    pub fn checked_mul(self, rhs: NzDecimal) -> Result<NzDecimal, NzError> {
+        let s = self.scale + rhs.scale;
+        if s > Self::MAX_SCALE {
+            return Err(NzError::Overflow);
+        }
+        Self::from_wide(self.mantissa.get() as i128 * rhs.mantissa.get() as i128, s)
+    }
+
+    /// Quotient at `scale` digits, rounded by `mode`.
+    /// Err(ZeroResult) if it rounds to 0, Err(Overflow) if it does not fit,
+    /// Err(OutOfRange) if scale > MAX_SCALE.
+    pub fn checked_div(
+        self,
+        rhs: NzDecimal,
+        scale: u8,
+        mode: Rounding,
+    ) -> Result<NzDecimal, NzError> {
+        if scale > Self::MAX_SCALE {
+            return Err(NzError::OutOfRange);
+        }
+        // q = a·10^-sa / (b·10^-sb) at 10^-scale  =>  q_m = a·10^(scale + sb - sa) / b
+        let k = scale as i32 + rhs.scale as i32 - self.scale as i32;
+        let (mut n, mut d) = (self.mantissa.get() as i128, rhs.mantissa.get() as i128);
+        if k >= 0 {
+            // k <= 36, |a| < 2^63: the product can exceed i128 only near the extremes.
+            n = n.checked_mul(pow10(k as u32)).ok_or(NzError::Overflow)?;
+        } else {
+            d *= pow10((-k) as u32);
+        }
+        Self::from_wide(div_round(n, d, mode), scale)
+    }
+
+    /// Same value at another scale; exact when growing, rounded by `mode` when shrinking.
+    pub fn rescale(self, scale: u8, mode: Rounding) -> Result<NzDecimal, NzError> {
+        if scale > Self::MAX_SCALE {
+            return Err(NzError::OutOfRange);
+        }
+        let m = self.mantissa.get() as i128;
+        let m = if scale >= self.scale {
+            m * pow10((scale - self.scale) as u32)
+        } else {
+            div_round(m, pow10((self.scale - scale) as u32), mode)
+        };
+        Self::from_wide(m, scale)
+    }
+
+    /// `x` at `scale` digits, rounded by `mode` from its exact binary value (0.1 is
+    /// 0.1000000000000000055..., so it becomes 0.1 with Nearest but 0.10000000000000001 at
+    /// scale 17 with Ceil).
+    /// Err(ZeroResult) if it rounds to 0, Err(OutOfRange) if it does not fit (including ±inf)
+    /// or scale > MAX_SCALE.
+    pub fn from_float(x: NzFloat, scale: u8, mode: Rounding) -> Result<NzDecimal, NzError> {
+        let (n, d) = Self::float_ratio(x, scale)?;
+        Self::from_wide(div_round(n, d, mode), scale).map_err(Self::fit_error)
+    }
+
+    /// `x` at `scale` digits without rounding. Err(Inexact) if its binary value has more
+    /// decimal digits than that (0.1, or 0.125 at scale 2); otherwise errors as from_float.
+    pub fn from_float_exact(x: NzFloat, scale: u8) -> Result<NzDecimal, NzError> {
+        let (n, d) = Self::float_ratio(x, scale)?;
+        if n % d != 0 {
+            return Err(NzError::Inexact);
+        }
+        Self::from_wide(n / d, scale).map_err(Self::fit_error)
+    }
+
+    /// x · 10^scale as an exact fraction n / d with d a power of two.
+    fn float_ratio(x: NzFloat, scale: u8) -> Result<(i128, i128), NzError> {
+        if scale > Self::MAX_SCALE || x.is_infinite() {
+            return Err(NzError::OutOfRange);
+        }
+        // x = ±mant · 2^exp exactly, with mant < 2^53.
+        let bits = x.get().to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        let frac = (bits & ((1 << 52) - 1)) as i128;
+        let (mant, exp) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | 1 << 52, biased - 1075)
+        };
+        let mant = if x.get() < 0.0 { -mant } else { mant };
+        // |mant · 10^scale| < 2^53 · 10^18 < 2^113.
+        let n = mant * pow10(scale as u32);
+        if exp >= 0 {
+            // exp > 14 means |x| >= 2^67, past i64 at any scale; it also keeps the shift inside i128.
+            if exp > 14 {
+                return Err(NzError::OutOfRange);
+            }
+            return Ok((n << exp, 1));
+        }
+        // A divisor above 2^114 makes |n / d| < 1/2 whatever n is; only its sign and the fact
+        // that it is inexact matter to the rounding, so shrink both to stay inside i128.
+        if -exp > 114 {
+            return Ok((n.signum(), 1 << 114));
+        }
+        Ok((n, 1 << -exp))
+    }
+
+    /// A result too large for the mantissa is OutOfRange here, as for NzFloat::checked_to_int.
+    #[inline]
+    fn fit_error(e: NzError) -> NzError {
+        if e == NzError::Overflow {
+            NzError::OutOfRange
+        } else {
+            e
+        }
+    }
+
+    /// Nearest f64 (may round; never 0 since |value| >= 1e-18).
+    #[inline]
+    pub fn to_float(self) -> NzFloat {
+        let v = self.mantissa.get() as f64 / pow10(self.scale as u32) as f64;
+        unsafe { NzFloat::new_unchecked(v) }
+    }
+
+    /// Mantissa and scale with trailing decimal zeros removed (the canonical form for Hash).
+    fn normalized(self) -> (i64, u8) {
+        let (mut m, mut s) = (self.mantissa.get(), self.scale);
+        while s > 0 && m % 10 == 0 {
+            m /= 10;
+            s -= 1;
+        }
+        (m, s)
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl From<NzInt> for NzDecimal {
+    #[inline]
+    fn from(x: NzInt) -> Self {
+        NzDecimal {
+            mantissa: x,
+            scale: 0,
+        }
+    }
+}
+
+impl PartialEq for NzDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for NzDecimal {}
+
+impl PartialOrd for NzDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NzDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = self.aligned(*other);
+        a.cmp(&b)
+    }
+}
+
+impl Hash for NzDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal values share one normalized form.
+        self.normalized().hash(state)
+    }
+}
+
+impl fmt::Debug for NzDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NzDecimal({self})")
+    }
+}
+
+impl fmt::Display for NzDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.mantissa.get() < 0 { "-" } else { "" };
+        let m = self.mantissa.abs_unsigned().get();
+        if self.scale == 0 {
+            return write!(f, "{sign}{m}");
+        }
+        let p = pow10(self.scale as u32) as u64;
+        write!(f, "{sign}{}.{:0w$}", m / p, m % p, w = self.scale as usize)
+    }
+}
+
+impl FromStr for NzDecimal {
+    type Err = NzParseError;
+    /// Accepts [+-]digits[.digits]; the number of fraction digits becomes the scale.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        let digits = int.trim_start_matches(['+', '-']);
+        let valid = |p: &str| p.bytes().all(|c| c.is_ascii_digit());
+        if int.len() - digits.len() > 1
+            || !valid(digits)
+            || !valid(frac)
+            || (digits.is_empty() && frac.is_empty())
+        {
+            return Err(NzParseError::Invalid);
+        }
+        let scale = u8::try_from(frac.len())
+            .ok()
+            .filter(|&s| s <= Self::MAX_SCALE)
+            .ok_or(NzParseError::Invalid)?;
+        // Accumulate in i128 and range-check once the sign is applied: i64::MIN has no
+        // positive counterpart, so a positive i64 accumulator would reject it.
+        let mut m: i128 = 0;
+        for c in digits.bytes().chain(frac.bytes()) {
+            m = m
+                .checked_mul(10)
+                .and_then(|m| m.checked_add((c - b'0') as i128))
+                .ok_or(NzParseError::Invalid)?;
+        }
+        let m = if int.starts_with('-') { -m } else { m };
+        let m = i64::try_from(m).map_err(|_| NzParseError::Invalid)?;
+        let mantissa = NzInt::new(m).ok_or(NzParseError::Zero)?;
+        Ok(NzDecimal { mantissa, scale })
+    }
+}
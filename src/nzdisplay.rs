@@ -0,0 +1,130 @@
+//! nzdisplay: Allocation-free decimal formatting for NzInt (itoa-style)
+//! API:
+//! - NzInt::display_buffer() -> NzIntBuffer (stack buffer, as_str())
+//! - NzInt::write_to(&mut impl core::fmt::Write)
+//! - format_signed(mag, sign, SignStyle) and NzInt/NzFloat::display_signed(SignStyle):
+//!   "+5", "−5", "(5)" etc. as lazy Display values
+//!
+//! Design choices:
+//! - Digits come from abs_unsigned(), so i64::MIN needs no special case
+//! - Bypasses core::fmt machinery entirely; usable in no_std and hot logging paths
+//! - Signed formatting returns a wrapper instead of a String, so it needs no alloc
+//!   and precision flags ({:.2}) apply to the magnitude
+
+use core::fmt;
+use core::num::NonZeroU64;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzsign::nzSign;
+
+/// Longest NzInt text: "-9223372036854775808".
+const MAX_LEN: usize = 20;
+
+/// Stack buffer holding the decimal text of an NzInt.
+#[derive(Clone, Copy)]
+pub struct NzIntBuffer {
+    buf: [u8; MAX_LEN],
+    start: usize,
+}
+
+impl NzIntBuffer {
+    /// The formatted text.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Only ASCII digits and '-' are ever written.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[self.start..]) }
+    }
+}
+
+impl fmt::Debug for NzIntBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NzIntBuffer").field(&self.as_str()).finish()
+    }
+}
+
+impl NzInt {
+    /// Format into a stack buffer without allocating.
+    #[inline]
+    pub fn display_buffer(self) -> NzIntBuffer {
+        let mut buf = [0u8; MAX_LEN];
+        let mut i = MAX_LEN;
+        let mut m = self.abs_unsigned().get();
+        // m >= 1, so at least one digit is written.
+        while m > 0 {
+            i -= 1;
+            buf[i] = b'0' + (m % 10) as u8;
+            m /= 10;
+        }
+        if self.get() < 0 {
+            i -= 1;
+            buf[i] = b'-';
+        }
+        NzIntBuffer { buf, start: i }
+    }
+
+    /// Write the decimal text to any fmt::Write sink without going through core::fmt.
+    #[inline]
+    pub fn write_to<W: fmt::Write>(self, w: &mut W) -> fmt::Result {
+        w.write_str(self.display_buffer().as_str())
+    }
+}
+
+/// How the sign of a number is rendered by format_signed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SignStyle {
+    /// "5" / "-5" (ASCII hyphen-minus; same as plain Display).
+    #[default]
+    Minus,
+    /// "+5" / "-5".
+    Plus,
+    /// "+5" / "−5" (typographic minus sign U+2212).
+    Typographic,
+    /// "5" / "(5)" (accounting style).
+    Parens,
+}
+
+/// Display wrapper produced by format_signed; renders `mag` with the sign in the chosen style.
+#[derive(Debug, Clone, Copy)]
+pub struct SignedDisplay<T> {
+    mag: T,
+    sign: nzSign,
+    style: SignStyle,
+}
+
+/// Pair a magnitude with a sign for display. `mag` is printed as-is, so pass the absolute value.
+#[inline]
+pub fn format_signed<T: fmt::Display>(mag: T, sign: nzSign, style: SignStyle) -> SignedDisplay<T> {
+    SignedDisplay { mag, sign, style }
+}
+
+impl<T: fmt::Display> fmt::Display for SignedDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (prefix, suffix) = match (self.style, self.sign) {
+            (SignStyle::Minus | SignStyle::Parens, nzSign::Pos) => ("", ""),
+            (SignStyle::Plus | SignStyle::Typographic, nzSign::Pos) => ("+", ""),
+            (SignStyle::Minus | SignStyle::Plus, nzSign::Neg) => ("-", ""),
+            (SignStyle::Typographic, nzSign::Neg) => ("\u{2212}", ""),
+            (SignStyle::Parens, nzSign::Neg) => ("(", ")"),
+        };
+        f.write_str(prefix)?;
+        self.mag.fmt(f)?;
+        f.write_str(suffix)
+    }
+}
+
+impl NzInt {
+    /// Display with the given sign style (e.g., "(42)" for -42 with SignStyle::Parens).
+    #[inline]
+    pub fn display_signed(self, style: SignStyle) -> SignedDisplay<NonZeroU64> {
+        format_signed(self.abs_unsigned(), self.sign(), style)
+    }
+}
+
+impl NzFloat {
+    /// Display with the given sign style; formatter precision applies to the magnitude.
+    #[inline]
+    pub fn display_signed(self, style: SignStyle) -> SignedDisplay<NzFloat> {
+        format_signed(self.abs(), self.sign(), style)
+    }
+}
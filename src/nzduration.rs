@@ -0,0 +1,142 @@
+//! nzduration: Non-zero signed time span in nanoseconds
+//! Invariants:
+//! - The span is never zero; it is an NzInt count of nanoseconds (about ±292 years)
+//! - Negative spans are allowed so that differences of timestamps stay in the type
+//!
+//! API:
+//! - NzDuration::from_nanos(i64) -> Option<Self>, from_nanos_nz(NzInt), as_nanos() -> NzInt
+//! - TryFrom<core::time::Duration> (Err(ZeroResult) / Err(Overflow)) and back to Duration
+//!   (Err(OutOfRange) for negative spans); unsigned_abs() -> Duration is total
+//! - checked_add/sub -> Result<Self, NzError>: Err(ZeroResult) when the spans cancel
+//! - div_duration(rhs) -> NzFloat, as_secs_f64() -> NzFloat, per_second() -> NzFloat (all total)
+//! - rate(work) -> Result<NzFloat, NzfError>: work per second
+//!
+//! Design choices:
+//! - Add/sub report Err(Overflow) rather than wrap: a wrapped span is a wrong answer, not a value
+//! - Ratios of two non-zero nanosecond counts lie in [2^-63, 2^63] in magnitude, so they
+//!   can neither underflow nor overflow an f64, and the conversions to f64 are total
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NzDuration(NzInt);
+
+impl NzDuration {
+    /// Span of `nanos` nanoseconds. Returns None if nanos == 0.
+    #[inline]
+    pub const fn from_nanos(nanos: i64) -> Option<Self> {
+        match NzInt::new(nanos) {
+            Some(n) => Some(NzDuration(n)),
+            None => None,
+        }
+    }
+
+    /// Span of a non-zero number of nanoseconds; total.
+    #[inline]
+    pub const fn from_nanos_nz(nanos: NzInt) -> Self {
+        NzDuration(nanos)
+    }
+
+    /// Length in nanoseconds.
+    #[inline]
+    pub const fn as_nanos(self) -> NzInt {
+        self.0
+    }
+
+    /// |self| as a core Duration; total.
+    #[inline]
+    pub fn unsigned_abs(self) -> Duration {
+        Duration::from_nanos(self.0.abs_unsigned().get())
+    }
+
+    /// Checked addition. Err(ZeroResult) if the spans cancel, Err(Overflow) past ±292 years.
+    #[inline]
+    pub fn checked_add(self, rhs: NzDuration) -> Result<NzDuration, NzError> {
+        let n = self
+            .0
+            .get()
+            .checked_add(rhs.0.get())
+            .ok_or(NzError::Overflow)?;
+        NzDuration::from_nanos(n).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked subtraction. Err(ZeroResult) if the spans are equal, Err(Overflow) past ±292 years.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzDuration) -> Result<NzDuration, NzError> {
+        let n = self
+            .0
+            .get()
+            .checked_sub(rhs.0.get())
+            .ok_or(NzError::Overflow)?;
+        NzDuration::from_nanos(n).ok_or(NzError::ZeroResult)
+    }
+
+    /// self / rhs as a ratio; total.
+    #[inline]
+    pub fn div_duration(self, rhs: NzDuration) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(self.0.get() as f64 / rhs.0.get() as f64) }
+    }
+
+    /// Length in seconds; total.
+    #[inline]
+    pub fn as_secs_f64(self) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(self.0.get() as f64 / 1e9) }
+    }
+
+    /// How many of these spans fit in one second (1 / seconds); total.
+    #[inline]
+    pub fn per_second(self) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(1e9 / self.0.get() as f64) }
+    }
+
+    /// Throughput: `work` per second of this span, i.e. work * per_second(). Fails like
+    /// NzFloat::checked_mul (e.g., Err(Infinite) under `strict-floats` if it overflows).
+    #[inline]
+    pub fn rate(self, work: NzFloat) -> Result<NzFloat, NzfError> {
+        work.checked_mul(self.per_second())
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl TryFrom<Duration> for NzDuration {
+    type Error = NzError;
+    /// Err(ZeroResult) for Duration::ZERO, Err(Overflow) above i64::MAX nanoseconds.
+    #[inline]
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        let n = i64::try_from(d.as_nanos()).map_err(|_| NzError::Overflow)?;
+        NzDuration::from_nanos(n).ok_or(NzError::ZeroResult)
+    }
+}
+
+impl TryFrom<NzDuration> for Duration {
+    type Error = NzError;
+    /// Err(OutOfRange) for negative spans; see NzDuration::unsigned_abs.
+    #[inline]
+    fn try_from(d: NzDuration) -> Result<Self, Self::Error> {
+        if d.0.get() < 0 {
+            return Err(NzError::OutOfRange);
+        }
+        Ok(d.unsigned_abs())
+    }
+}
+
+impl fmt::Debug for NzDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NzDuration({self})")
+    }
+}
+
+impl fmt::Display for NzDuration {
+    /// Like Duration's Debug output ("1.5ms"), with a '-' for negative spans.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.get() < 0 {
+            f.write_str("-")?;
+        }
+        fmt::Debug::fmt(&self.unsigned_abs(), f)
+    }
+}
@@ -0,0 +1,363 @@
+//! nzf32: Non-zero, non-NaN 32-bit float
+//! Invariants:
+//! - Value is finite or infinite, but never 0.0, -0.0, or NaN (same as nzfloat)
+//!
+//! API (mirrors NzFloat):
+//! - const new/new_unchecked/get/one/neg_one/abs/signum, new_finite/new_normal, is_finite/
+//!   is_infinite/is_subnormal/is_normal
+//! - checked_add/sub/mul/div, operators (+ - * / and assign forms panic like NzFloat's; Neg is total)
+//! - Eq/Ord/Hash (to_stable_bytes under `stable-hash`), comparisons with raw f32
+//! - From<NzF32> for NzFloat (lossless widening)
+//! - TryFrom<NzFloat> for NzF32 (narrowing; Err(ZeroResult) if the value rounds to zero)
+//! - from_f16_checked/to_f16_checked on NzF32 and NzFloat (`half` feature)
+//!
+//! Design choices:
+//! - Deliberately not mirrored: the math-library methods (sqrt, ln, exp, trig, powf, pow10),
+//!   conversions to NzInt, lerp/midpoint, saturating_* and the sort-key encoding. f32 is the
+//!   storage type; widen to NzFloat (lossless) for computation and narrow back with try_from
+//! - TryFrom<f32> reports NzfError (ZeroResult for ±0.0, NotANumber for NaN, as new_finite does)
+//!   rather than NzfParseError, whose payload is f64 bits
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::nzfloat::{NzFloat, NzfError, unwrap_op};
+
+/// The 64-bit member of the float family.
+pub type NzF64 = NzFloat;
+
+/// repr(transparent), like NzFloat: validated f32 buffers can be viewed as NzF32 buffers.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct NzF32(f32);
+
+const _: () = {
+    assert!(size_of::<NzF32>() == size_of::<f32>());
+    assert!(align_of::<NzF32>() == align_of::<f32>());
+};
+
+impl NzF32 {
+    /// Create from f32; rejects 0.0, -0.0, NaN.
+    #[inline]
+    pub const fn new(v: f32) -> Option<Self> {
+        // Same bit test as NzFloat::new, for the 32-bit layout.
+        let magnitude = v.to_bits() & 0x7FFF_FFFF;
+        if magnitude == 0 || magnitude > 0x7F80_0000 {
+            None
+        } else {
+            Some(NzF32(v))
+        }
+    }
+
+    /// Create without checks. Caller must ensure v != 0.0 and !NaN.
+    /// # Safety
+    /// Passing 0.0/-0.0/NaN breaks invariants.
+    #[inline]
+    pub const unsafe fn new_unchecked(v: f32) -> Self {
+        NzF32(v)
+    }
+
+    /// Get inner f32.
+    #[inline]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
+
+    /// Create from a finite f32: Err(ZeroResult) for ±0.0, Err(NotANumber) for NaN,
+    /// Err(Infinite) for ±inf, regardless of `strict-floats`.
+    #[inline]
+    pub const fn new_finite(v: f32) -> Result<Self, NzfError> {
+        if v.is_infinite() {
+            return Err(NzfError::Infinite);
+        }
+        match NzF32::new(v) {
+            Some(x) => Ok(x),
+            None if v.is_nan() => Err(NzfError::NotANumber),
+            None => Err(NzfError::ZeroResult),
+        }
+    }
+
+    /// Like new_finite, and also Err(Underflow) for f32 subnormals, regardless of `deny-subnormals`.
+    #[inline]
+    pub const fn new_normal(v: f32) -> Result<Self, NzfError> {
+        if v.is_subnormal() {
+            return Err(NzfError::Underflow);
+        }
+        NzF32::new_finite(v)
+    }
+
+    /// True unless the value is ±inf.
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// True for ±inf.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// True for magnitudes below f32::MIN_POSITIVE.
+    #[inline]
+    pub const fn is_subnormal(self) -> bool {
+        self.0.is_subnormal()
+    }
+
+    /// True for finite values that are not subnormal.
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+
+    /// Checked addition.
+    #[inline]
+    pub fn checked_add(self, rhs: NzF32) -> Result<NzF32, NzfError> {
+        Self::check(self.0 + rhs.0)
+    }
+
+    /// Checked subtraction.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzF32) -> Result<NzF32, NzfError> {
+        Self::check(self.0 - rhs.0)
+    }
+
+    /// Checked multiplication.
+    #[inline]
+    pub fn checked_mul(self, rhs: NzF32) -> Result<NzF32, NzfError> {
+        Self::check(self.0 * rhs.0)
+    }
+
+    /// Checked division (IEEE-754, allows ±inf).
+    #[inline]
+    pub fn checked_div(self, rhs: NzF32) -> Result<NzF32, NzfError> {
+        // rhs is guaranteed non-zero by invariant
+        Self::check(self.0 / rhs.0)
+    }
+
+    /// Absolute value.
+    #[inline]
+    pub const fn abs(self) -> NzF32 {
+        // abs(x) != 0.0 because x != 0.0
+        NzF32(self.0.abs())
+    }
+
+    /// Sign as ±1.0 (non-zero).
+    #[inline]
+    pub const fn signum(self) -> NzF32 {
+        if self.0.is_sign_positive() {
+            NzF32::one()
+        } else {
+            NzF32::neg_one()
+        }
+    }
+
+    /// Stable byte encoding: the IEEE-754 bit pattern as 4 little-endian bytes.
+    /// With the `stable-hash` feature, `Hash` writes exactly these bytes.
+    #[inline]
+    pub fn to_stable_bytes(self) -> [u8; 4] {
+        self.0.to_bits().to_le_bytes()
+    }
+
+    /// Construct +1.0.
+    #[inline]
+    pub const fn one() -> NzF32 {
+        NzF32(1.0)
+    }
+
+    /// Construct -1.0.
+    #[inline]
+    pub const fn neg_one() -> NzF32 {
+        NzF32(-1.0)
+    }
+
+    #[inline]
+    fn check(r: f32) -> Result<NzF32, NzfError> {
+        // Classified in f32: an f32 subnormal would be a normal f64.
+        match NzfError::classify(r.is_nan(), r == 0.0, r.is_infinite(), r.is_subnormal()) {
+            None => Ok(NzF32(r)),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+/* ----- Half precision (feature `half`) ----- */
+
+#[cfg(feature = "half")]
+impl NzF32 {
+    /// Widen an f16; lossless. Err(ZeroResult) for ±0.0, Err(NotANumber) for NaN.
+    #[inline]
+    pub fn from_f16_checked(v: half::f16) -> Result<NzF32, NzfError> {
+        NzF32::check(v.to_f32())
+    }
+
+    /// Narrow to f16 (round to nearest). Err(ZeroResult) if the value flushes to ±0.0
+    /// (|v| below about 3e-8); overflow yields ±inf (Err(Infinite) with `strict-floats`).
+    #[inline]
+    pub fn to_f16_checked(self) -> Result<half::f16, NzfError> {
+        let h = half::f16::from_f32(self.0);
+        NzF32::check(h.to_f32()).map(|_| h)
+    }
+}
+
+#[cfg(feature = "half")]
+impl NzFloat {
+    /// Widen an f16; lossless. Err(ZeroResult) for ±0.0, Err(NotANumber) for NaN.
+    #[inline]
+    pub fn from_f16_checked(v: half::f16) -> Result<NzFloat, NzfError> {
+        NzF32::from_f16_checked(v).map(NzFloat::from)
+    }
+
+    /// Narrow to f16 (round to nearest, directly from f64). Same errors as NzF32::to_f16_checked.
+    #[inline]
+    pub fn to_f16_checked(self) -> Result<half::f16, NzfError> {
+        let h = half::f16::from_f64(self.get());
+        NzF32::check(h.to_f32()).map(|_| h)
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Debug for NzF32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NzF32").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for NzF32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq for NzF32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl Eq for NzF32 {}
+
+impl PartialOrd for NzF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NzF32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // No NaN in domain -> total_cmp is a strict total order
+        self.0.total_cmp(&other.0)
+    }
+}
+
+// Comparisons with raw f32 follow IEEE-754: NaN compares unequal and unordered.
+impl PartialEq<f32> for NzF32 {
+    fn eq(&self, other: &f32) -> bool {
+        self.0 == *other
+    }
+}
+impl PartialEq<NzF32> for f32 {
+    fn eq(&self, other: &NzF32) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<f32> for NzF32 {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+impl PartialOrd<NzF32> for f32 {
+    fn partial_cmp(&self, other: &NzF32) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl Hash for NzF32 {
+    #[cfg(not(feature = "stable-hash"))]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // No NaN and no ±0.0 -> to_bits is stable
+        self.0.to_bits().hash(state)
+    }
+    #[cfg(feature = "stable-hash")]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_stable_bytes())
+    }
+}
+
+impl TryFrom<f32> for NzF32 {
+    type Error = NzfError;
+    #[inline]
+    fn try_from(v: f32) -> Result<Self, Self::Error> {
+        match NzF32::new(v) {
+            Some(x) => Ok(x),
+            None if v.is_nan() => Err(NzfError::NotANumber),
+            None => Err(NzfError::ZeroResult),
+        }
+    }
+}
+
+impl From<NzF32> for f32 {
+    #[inline]
+    fn from(v: NzF32) -> f32 {
+        v.0
+    }
+}
+
+impl From<NzF32> for NzFloat {
+    /// Lossless widening: every f32 is exactly representable as f64.
+    #[inline]
+    fn from(v: NzF32) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(f64::from(v.0)) }
+    }
+}
+
+impl TryFrom<NzFloat> for NzF32 {
+    type Error = NzfError;
+    /// Narrowing rounds to nearest; magnitudes below the f32 subnormal range
+    /// round to ±0.0 and are reported as Err(ZeroResult). Overflow yields ±inf
+    /// (Err(Infinite) with the `strict-floats` feature).
+    #[inline]
+    fn try_from(v: NzFloat) -> Result<Self, Self::Error> {
+        NzF32::check(v.get() as f32)
+    }
+}
+
+/* ----- Operator traits (panic on zero/NaN result; use checked_* for fallible paths) ----- */
+
+macro_rules! nzf32_binop {
+    ($tr:ident, $f:ident, $checked:ident, $verb:literal, $atr:ident, $af:ident) => {
+        impl $tr for NzF32 {
+            type Output = NzF32;
+            #[inline]
+            #[track_caller]
+            fn $f(self, rhs: NzF32) -> NzF32 {
+                unwrap_op(self.$checked(rhs), $verb)
+            }
+        }
+
+        impl $atr for NzF32 {
+            #[inline]
+            #[track_caller]
+            fn $af(&mut self, rhs: NzF32) {
+                *self = $tr::$f(*self, rhs);
+            }
+        }
+    };
+}
+
+nzf32_binop!(Add, add, checked_add, "add", AddAssign, add_assign);
+nzf32_binop!(Sub, sub, checked_sub, "subtract", SubAssign, sub_assign);
+nzf32_binop!(Mul, mul, checked_mul, "multiply", MulAssign, mul_assign);
+nzf32_binop!(Div, div, checked_div, "divide", DivAssign, div_assign);
+
+impl Neg for NzF32 {
+    type Output = NzF32;
+    /// Total: negation never produces 0.0 or NaN.
+    #[inline]
+    fn neg(self) -> NzF32 {
+        NzF32(-self.0)
+    }
+}
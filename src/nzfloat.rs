@@ -1,94 +1,580 @@
 //! nzfloat: Non-zero, non-NaN 64-bit float
 //! Invariants:
 //! - Value is finite or infinite, but never 0.0, -0.0, or NaN
+//!
 //! API:
-//! - NzFloat::new(v) -> Option<Self>
+//! - NzFloat::new(v) -> Option<Self>; new_finite(v) / new_normal(v) -> Result also reject ±inf / subnormals
+//! - is_finite(), is_infinite(), is_subnormal(), is_normal(), classify() -> NzFpCategory
 //! - get(), checked_add/sub/mul/div, abs(), signum()
-//! - TryFrom<f64>, Display/Debug/Ord/Hash
+//! - checked_lerp(), checked_midpoint()
+//! - checked_sqrt/ln/log10/exp/powf/sin/cos/tan, checked_to_int(Rounding), checked_mul/div_pow10
+//!   (need the `std` or `libm` feature)
+//! - checked_recip(), checked_mul/div_pow2
+//! - is_integer_valued(), to_int_exact() (lossless float -> int, no rounding)
+//! - saturating_add/sub/mul/div (never fail; zero snaps to ±smallest subnormal)
+//! - TryFrom<f64> (Err(NzfParseError) with the rejected bits), Display/Debug/Ord/Hash
 
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::nzint::{NzError, NzInt};
+use crate::nzsign::nzSign;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NzfError {
     ZeroResult,     // result is 0.0 or -0.0
     NotANumber,     // NaN encountered
+    Infinite,       // ±inf (from new_finite/new_normal, or any result with `strict-floats`)
+    Underflow,      // subnormal (from new_normal, or any result with `deny-subnormals`)
+}
+
+impl fmt::Display for NzfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NzfError::ZeroResult => "result is 0.0 or -0.0",
+            NzfError::NotANumber => "result is NaN",
+            NzfError::Infinite => "result is infinite",
+            NzfError::Underflow => "result is subnormal",
+        })
+    }
+}
+
+impl core::error::Error for NzfError {}
+
+impl NzfError {
+    /// The checked-op rules for any float result, given its classification: NaN -> NotANumber,
+    /// zero -> ZeroResult, and with `strict-floats` / `deny-subnormals` also ±inf -> Infinite /
+    /// subnormal -> Underflow. None if the value is accepted. Every float type and composite
+    /// (NzF32, NzComplex, NzVec, NzMat, batches) validates results through this, so the feature
+    /// rules live here only.
+    #[inline]
+    pub(crate) const fn classify(
+        nan: bool,
+        zero: bool,
+        infinite: bool,
+        subnormal: bool,
+    ) -> Option<NzfError> {
+        if nan {
+            Some(NzfError::NotANumber)
+        } else if zero {
+            Some(NzfError::ZeroResult)
+        } else if cfg!(feature = "strict-floats") && infinite {
+            Some(NzfError::Infinite)
+        } else if cfg!(feature = "deny-subnormals") && subnormal {
+            Some(NzfError::Underflow)
+        } else {
+            None
+        }
+    }
+}
+
+/// Error for constructing an NzFloat from a raw f64; carries the rejected input's bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NzfParseError {
+    /// The input was 0.0 or -0.0 (the sign is kept in the bits).
+    ZeroInput(u64),
+    /// The input was NaN (payload and sign are kept in the bits).
+    NaNInput(u64),
+}
+
+impl NzfParseError {
+    /// Raw IEEE-754 bits of the rejected input.
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        match self {
+            NzfParseError::ZeroInput(b) | NzfParseError::NaNInput(b) => b,
+        }
+    }
+
+    /// The rejected input as f64.
+    #[inline]
+    pub const fn value(self) -> f64 {
+        f64::from_bits(self.bits())
+    }
+}
+
+impl fmt::Display for NzfParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NzfParseError::ZeroInput(_) => write!(f, "input is {:?}", self.value()),
+            NzfParseError::NaNInput(b) => write!(f, "input is NaN (bits {b:#018x})"),
+        }
+    }
+}
+
+impl core::error::Error for NzfParseError {}
+
+/// Rounding policy for float -> integer conversion (and NzDecimal rescaling and from_float).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    Floor,   // toward -inf
+    Ceil,    // toward +inf
+    Trunc,   // toward zero
+    Nearest, // to nearest, ties to even
+}
+
+/// Floating-point class of an NzFloat; unlike core::num::FpCategory it has no Zero or Nan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NzFpCategory {
+    Normal,
+    Subnormal,
+    Infinite,
 }
 
+/// repr(transparent): validated f64 buffers can be viewed as NzFloat buffers.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct NzFloat(f64);
 
+// Same size and alignment as f64. There is no niche: every bit pattern NzFloat excludes is
+// also a valid f64 the compiler cannot know about, so Option<NzFloat> is larger.
+const _: () = {
+    assert!(size_of::<NzFloat>() == size_of::<f64>());
+    assert!(align_of::<NzFloat>() == align_of::<f64>());
+};
+
 impl NzFloat {
     /// Create from f64; rejects 0.0, -0.0, NaN.
     #[inline]
-    pub fn new(v: f64) -> Option<Self> {
-        if v == 0.0 || v.is_nan() { None } else { Some(NzFloat(v)) }
+    pub const fn new(v: f64) -> Option<Self> {
+        // Bit inspection keeps this usable in const contexts:
+        // ±0.0 has all non-sign bits clear; NaN has exponent all ones and a non-zero mantissa.
+        let magnitude = v.to_bits() & 0x7FFF_FFFF_FFFF_FFFF;
+        if magnitude == 0 || magnitude > 0x7FF0_0000_0000_0000 {
+            None
+        } else {
+            Some(NzFloat(v))
+        }
+    }
+
+    /// Create from f64, snapping ±0.0 (of either sign) to the smallest subnormal of sign
+    /// `direction` (±5e-324). Err(NotANumber) for NaN; every other value is kept as is.
+    #[inline]
+    pub const fn new_snap(v: f64, direction: nzSign) -> Result<Self, NzfError> {
+        if v.is_nan() {
+            return Err(NzfError::NotANumber);
+        }
+        match NzFloat::new(v) {
+            Some(x) => Ok(x),
+            None => {
+                let tiny = f64::from_bits(1);
+                Ok(NzFloat(if matches!(direction, nzSign::Pos) {
+                    tiny
+                } else {
+                    -tiny
+                }))
+            }
+        }
     }
 
     /// Create without checks. Caller must ensure v != 0.0 and !NaN.
     /// # Safety
     /// Passing 0.0/-0.0/NaN breaks invariants.
     #[inline]
-    pub unsafe fn new_unchecked(v: f64) -> Self {
+    pub const unsafe fn new_unchecked(v: f64) -> Self {
         NzFloat(v)
     }
 
     /// Get inner f64.
     #[inline]
-    pub fn get(self) -> f64 {
+    pub const fn get(self) -> f64 {
         self.0
     }
 
+    /// Create from a finite f64: Err(ZeroResult) for ±0.0, Err(NotANumber) for NaN,
+    /// Err(Infinite) for ±inf, regardless of `strict-floats`.
+    #[inline]
+    pub const fn new_finite(v: f64) -> Result<Self, NzfError> {
+        if v.is_infinite() {
+            return Err(NzfError::Infinite);
+        }
+        match NzFloat::new(v) {
+            Some(x) => Ok(x),
+            None if v.is_nan() => Err(NzfError::NotANumber),
+            None => Err(NzfError::ZeroResult),
+        }
+    }
+
+    /// Like new_finite, and also Err(Underflow) for subnormals, regardless of `deny-subnormals`.
+    /// Use where underflow to a subnormal should count as zero.
+    #[inline]
+    pub const fn new_normal(v: f64) -> Result<Self, NzfError> {
+        if v.is_subnormal() {
+            return Err(NzfError::Underflow);
+        }
+        NzFloat::new_finite(v)
+    }
+
+    /// True unless the value is ±inf.
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// True for ±inf.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// True for magnitudes below f64::MIN_POSITIVE (the smallest is 5e-324).
+    #[inline]
+    pub const fn is_subnormal(self) -> bool {
+        self.0.is_subnormal()
+    }
+
+    /// True for finite, non-subnormal values.
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+
+    /// Floating-point class; zero and NaN cannot occur.
+    #[inline]
+    pub const fn classify(self) -> NzFpCategory {
+        if self.0.is_infinite() {
+            NzFpCategory::Infinite
+        } else if self.0.is_subnormal() {
+            NzFpCategory::Subnormal
+        } else {
+            NzFpCategory::Normal
+        }
+    }
+
     /// Checked addition.
     #[inline]
     pub fn checked_add(self, rhs: NzFloat) -> Result<NzFloat, NzfError> {
-        let r = self.0 + rhs.0;
-        if r.is_nan() { return Err(NzfError::NotANumber); }
-        if r == 0.0 { return Err(NzfError::ZeroResult); }
-        Ok(unsafe { NzFloat::new_unchecked(r) })
+        NzFloat::check(self.0 + rhs.0)
     }
 
     /// Checked subtraction.
     #[inline]
     pub fn checked_sub(self, rhs: NzFloat) -> Result<NzFloat, NzfError> {
-        let r = self.0 - rhs.0;
-        if r.is_nan() { return Err(NzfError::NotANumber); }
-        if r == 0.0 { return Err(NzfError::ZeroResult); }
-        Ok(unsafe { NzFloat::new_unchecked(r) })
+        NzFloat::check(self.0 - rhs.0)
     }
 
     /// Checked multiplication.
     #[inline]
     pub fn checked_mul(self, rhs: NzFloat) -> Result<NzFloat, NzfError> {
-        let r = self.0 * rhs.0;
-        if r.is_nan() { return Err(NzfError::NotANumber); }
-        if r == 0.0 { return Err(NzfError::ZeroResult); }
-        Ok(unsafe { NzFloat::new_unchecked(r) })
+        NzFloat::check(self.0 * rhs.0)
     }
 
-    /// Checked division (IEEE-754, allows ±inf).
+    /// Checked division (IEEE-754, allows ±inf unless `strict-floats` is enabled).
     #[inline]
     pub fn checked_div(self, rhs: NzFloat) -> Result<NzFloat, NzfError> {
         // rhs is guaranteed non-zero by invariant
-        let r = self.0 / rhs.0;
-        if r.is_nan() { return Err(NzfError::NotANumber); }
-        if r == 0.0 { return Err(NzfError::ZeroResult); }
-        Ok(unsafe { NzFloat::new_unchecked(r) })
+        NzFloat::check(self.0 / rhs.0)
+    }
+
+    /// Checked addition of a raw f64, which may be ±0.0 (giving self) or NaN (Err(NotANumber)).
+    #[inline]
+    pub fn checked_add_f64(self, rhs: f64) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0 + rhs)
+    }
+
+    /// Checked subtraction of a raw f64; see checked_add_f64.
+    #[inline]
+    pub fn checked_sub_f64(self, rhs: f64) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0 - rhs)
+    }
+
+    /// Checked multiplication by a raw f64. Err(ZeroResult) if rhs is ±0.0, or NaN for inf * 0.
+    #[inline]
+    pub fn checked_mul_f64(self, rhs: f64) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0 * rhs)
+    }
+
+    /// Checked division by a raw f64. Division by ±0.0 gives ±inf, as with f64
+    /// (Err(Infinite) under `strict-floats`).
+    #[inline]
+    pub fn checked_div_f64(self, rhs: f64) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0 / rhs)
+    }
+
+    /// Checked fused `self * a + b` with a single rounding. Only the final result is checked,
+    /// so a product that would underflow on its own still contributes.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_mul_add(self, a: NzFloat, b: NzFloat) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::mul_add(self.0, a.0, b.0))
+    }
+
+    /// Checked |self - other|. Err(ZeroResult) if the operands are equal, Err(NotANumber) for
+    /// two infinities of the same sign.
+    #[inline]
+    pub fn checked_abs_diff(self, other: NzFloat) -> Result<NzFloat, NzfError> {
+        NzFloat::check((self.0 - other.0).abs())
+    }
+
+    /// Checked linear interpolation `self + t * (other - self)`.
+    /// Uses the monotone formulation: exact at t == 0 and t == 1, monotone in t,
+    /// and within [self, other] for t in [0, 1]. Errors if the result is 0.0 or NaN.
+    #[inline]
+    pub fn checked_lerp(self, other: NzFloat, t: f64) -> Result<NzFloat, NzfError> {
+        let (a, b) = (self.0, other.0);
+        let r = if a.is_sign_positive() != b.is_sign_positive() {
+            // Opposite signs: b - a cannot cancel, and this form is exact at both endpoints.
+            t * b + (1.0 - t) * a
+        } else if t == 1.0 {
+            b
+        } else {
+            // Same sign: exact at t == 0; clamping against b keeps the result monotone.
+            let x = a + t * (b - a);
+            if (t > 1.0) == (b > a) {
+                b.max(x)
+            } else {
+                b.min(x)
+            }
+        };
+        NzFloat::check(r)
+    }
+
+    /// Checked midpoint `(self + other) / 2`, computed without overflow at extreme exponents.
+    /// Errors if the result is 0.0 (e.g., x and -x) or NaN (inf and -inf).
+    #[inline]
+    pub fn checked_midpoint(self, other: NzFloat) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0.midpoint(other.0))
+    }
+
+    /// Checked square root. Negative inputs yield Err(NotANumber).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_sqrt(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::sqrt(self.0))
+    }
+
+    /// Checked natural log. ln(1) == 0 yields Err(ZeroResult); negative inputs Err(NotANumber).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_ln(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::ln(self.0))
+    }
+
+    /// Checked base-10 log. log10(1) == 0 yields Err(ZeroResult); negative inputs Err(NotANumber).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_log10(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::log10(self.0))
+    }
+
+    /// Checked e^x. Underflow to 0.0 (x below about -745) yields Err(ZeroResult).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_exp(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::exp(self.0))
+    }
+
+    /// Checked self^exp. Underflow yields Err(ZeroResult); a negative base with a
+    /// non-integer exponent yields Err(NotANumber).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_powf(self, exp: NzFloat) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::powf(self.0, exp.0))
+    }
+
+    /// Checked reciprocal 1/x. Only ±inf fails (1/inf == 0.0 -> Err(ZeroResult)).
+    #[inline]
+    pub fn checked_recip(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.0.recip())
+    }
+
+    /// Checked sine. Err(ZeroResult) if the result rounds to 0.0, Err(NotANumber) for ±inf.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_sin(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::sin(self.0))
+    }
+
+    /// Checked cosine. Err(ZeroResult) if the result rounds to 0.0, Err(NotANumber) for ±inf.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_cos(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::cos(self.0))
+    }
+
+    /// Checked tangent. Err(ZeroResult) if the result rounds to 0.0, Err(NotANumber) for ±inf.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_tan(self) -> Result<NzFloat, NzfError> {
+        NzFloat::check(math::tan(self.0))
+    }
+
+    /// Checked scaling by 10^exp. Negative exponents divide by 10^-exp, which rounds
+    /// better than multiplying by the inexact 10^exp.
+    /// Underflow yields Err(ZeroResult); overflow yields ±inf (Err(Infinite) with `strict-floats`).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_mul_pow10(self, exp: i32) -> Result<NzFloat, NzfError> {
+        // 10^400 is already inf; clamping keeps powi's argument in range.
+        let p = math::powi(10.0, exp.unsigned_abs().min(400) as i32);
+        NzFloat::check(if exp >= 0 { self.0 * p } else { self.0 / p })
+    }
+
+    /// Checked division by 10^exp; equivalent to checked_mul_pow10(-exp).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_div_pow10(self, exp: i32) -> Result<NzFloat, NzfError> {
+        let p = math::powi(10.0, exp.unsigned_abs().min(400) as i32);
+        NzFloat::check(if exp >= 0 { self.0 / p } else { self.0 * p })
+    }
+
+    /// Checked scaling by 2^exp (ldexp); exact unless the result overflows or becomes subnormal.
+    /// Underflow yields Err(ZeroResult); overflow yields ±inf (Err(Infinite) with `strict-floats`).
+    #[inline]
+    pub fn checked_mul_pow2(self, exp: i32) -> Result<NzFloat, NzfError> {
+        let mut r = self.0;
+        // Clamp: any shift beyond ±2200 saturates to inf/0 anyway.
+        let mut e = exp.clamp(-2200, 2200);
+        while e > 1023 {
+            r *= f64::from_bits(0x7FE0_0000_0000_0000); // 2^1023
+            e -= 1023;
+        }
+        while e < -1022 {
+            r *= f64::MIN_POSITIVE; // 2^-1022
+            e += 1022;
+        }
+        // 2^e is a normal f64 for e in -1022..=1023.
+        NzFloat::check(r * f64::from_bits(((e + 1023) as u64) << 52))
+    }
+
+    /// Checked division by 2^exp; equivalent to checked_mul_pow2(-exp).
+    #[inline]
+    pub fn checked_div_pow2(self, exp: i32) -> Result<NzFloat, NzfError> {
+        self.checked_mul_pow2(exp.saturating_neg())
+    }
+
+    /// Round to an integer with the given policy.
+    /// Returns:
+    /// - Err(ZeroResult) if the rounded value is zero (e.g., 0.4 with Trunc/Nearest).
+    /// - Err(OutOfRange) if it does not fit in i64 (including ±inf).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_to_int(self, mode: Rounding) -> Result<NzInt, NzError> {
+        let r = match mode {
+            Rounding::Floor => math::floor(self.0),
+            Rounding::Ceil => math::ceil(self.0),
+            Rounding::Trunc => math::trunc(self.0),
+            Rounding::Nearest => math::round_ties_even(self.0),
+        };
+        if !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&r) {
+            return Err(NzError::OutOfRange);
+        }
+        NzInt::new(r as i64).ok_or(NzError::ZeroResult)
+    }
+
+    /// True if the value is finite and has no fractional part (any magnitude, not just i64 range).
+    #[inline]
+    pub fn is_integer_valued(self) -> bool {
+        // Every finite f64 with |v| >= 2^52 is an integer; below that `as i64` is exact truncation.
+        let a = self.0.abs();
+        a.is_finite() && (a >= 4_503_599_627_370_496.0 || (self.0 as i64) as f64 == self.0)
+    }
+
+    /// Convert to NzInt without rounding.
+    /// Returns:
+    /// - Err(Inexact) if the value has a fractional part (or is ±inf).
+    /// - Err(OutOfRange) if it is integer-valued but does not fit in i64.
+    #[inline]
+    pub fn to_int_exact(self) -> Result<NzInt, NzError> {
+        if !self.is_integer_valued() {
+            return Err(NzError::Inexact);
+        }
+        if !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&self.0) {
+            return Err(NzError::OutOfRange);
+        }
+        // Integer-valued and non-zero, so |v| >= 1 and the cast cannot produce 0.
+        Ok(unsafe { NzInt::new_unchecked(self.0 as i64) })
+    }
+
+    /// Saturating addition; never fails.
+    /// An exact zero sum (self == -rhs) snaps to the smallest subnormal (±5e-324)
+    /// with the sign of self. ±inf results are kept (inf is in the domain).
+    /// inf + -inf has no nearest value and yields ±1.0 with the sign of self.
+    #[inline]
+    pub fn saturating_add(self, rhs: NzFloat) -> NzFloat {
+        Self::snap(self.0 + rhs.0, self.0.is_sign_positive())
+    }
+
+    /// Saturating subtraction; never fails.
+    /// An exact zero difference (self == rhs) snaps to the smallest subnormal (±5e-324)
+    /// with the sign of self. inf - inf yields ±1.0 with the sign of self.
+    #[inline]
+    pub fn saturating_sub(self, rhs: NzFloat) -> NzFloat {
+        Self::snap(self.0 - rhs.0, self.0.is_sign_positive())
+    }
+
+    /// Saturating multiplication; never fails.
+    /// Underflow to zero snaps to the smallest subnormal (±5e-324) with the sign of
+    /// the exact product (positive iff the operands have the same sign).
+    #[inline]
+    pub fn saturating_mul(self, rhs: NzFloat) -> NzFloat {
+        Self::snap(
+            self.0 * rhs.0,
+            self.0.is_sign_positive() == rhs.0.is_sign_positive(),
+        )
+    }
+
+    /// Saturating division; never fails.
+    /// Underflow to zero (e.g., 1e-300 / 1e300) snaps to the smallest subnormal (±5e-324)
+    /// with the sign of the exact quotient. inf / inf yields ±1.0 with that sign.
+    #[inline]
+    pub fn saturating_div(self, rhs: NzFloat) -> NzFloat {
+        Self::snap(
+            self.0 / rhs.0,
+            self.0.is_sign_positive() == rhs.0.is_sign_positive(),
+        )
+    }
+
+    /// Validate a raw checked-op result: 0.0 -> ZeroResult, NaN -> NotANumber,
+    /// with the `strict-floats` feature ±inf -> Infinite,
+    /// and with the `deny-subnormals` feature a subnormal -> Underflow.
+    #[inline]
+    pub(crate) fn check(r: f64) -> Result<NzFloat, NzfError> {
+        match NzfError::classify(r.is_nan(), r == 0.0, r.is_infinite(), r.is_subnormal()) {
+            None => Ok(NzFloat(r)),
+            Some(e) => Err(e),
+        }
+    }
+
+    /// Map a raw result into the domain: 0.0 -> ±smallest subnormal, NaN -> ±1.0.
+    #[inline]
+    fn snap(r: f64, positive: bool) -> NzFloat {
+        let v = if r.is_nan() {
+            1.0
+        } else if r == 0.0 {
+            f64::from_bits(1) // smallest positive subnormal, 5e-324
+        } else {
+            return NzFloat(r);
+        };
+        NzFloat(if positive { v } else { -v })
     }
 
     /// Absolute value.
     #[inline]
-    pub fn abs(self) -> NzFloat {
+    pub const fn abs(self) -> NzFloat {
         // abs(x) != 0.0 because x != 0.0
         let r = self.0.abs();
         debug_assert!(r != 0.0 && !r.is_nan());
         unsafe { NzFloat::new_unchecked(r) }
     }
 
+    /// Clamp |self| into [|min|, |max|], keeping the sign of self. Total: the result is
+    /// never zero because |min| > 0. The bounds' own signs are ignored.
+    /// # Panics
+    /// Panics if |min| > |max|, like f64::clamp.
+    #[inline]
+    pub fn clamp_magnitude(self, min: NzFloat, max: NzFloat) -> NzFloat {
+        let m = self.0.abs().clamp(min.0.abs(), max.0.abs());
+        NzFloat(m.copysign(self.0))
+    }
+
     /// Sign as ±1.0 (non-zero).
     #[inline]
-    pub fn signum(self) -> NzFloat {
+    pub const fn signum(self) -> NzFloat {
         if self.0.is_sign_positive() {
             unsafe { NzFloat::new_unchecked(1.0) }
         } else {
@@ -96,15 +582,48 @@ impl NzFloat {
         }
     }
 
+    /// Stable byte encoding: the IEEE-754 bit pattern as 8 little-endian bytes.
+    /// This definition is fixed across platforms and releases; with the `stable-hash`
+    /// feature, `Hash` writes exactly these bytes.
+    #[inline]
+    pub fn to_stable_bytes(self) -> [u8; 8] {
+        self.0.to_bits().to_le_bytes()
+    }
+
+    /// Sort-key encoding: big-endian bits with negatives inverted and positives' sign bit set,
+    /// so memcmp order matches Ord (-inf < ... < -min < +min < ... < +inf).
+    #[inline]
+    pub const fn to_order_preserving_bytes(self) -> [u8; 8] {
+        let bits = self.0.to_bits();
+        let key = if bits >> 63 == 1 {
+            !bits
+        } else {
+            bits | (1 << 63)
+        };
+        key.to_be_bytes()
+    }
+
+    /// Inverse of to_order_preserving_bytes. Returns None for encodings of ±0.0 or NaN.
+    #[inline]
+    pub const fn from_order_preserving_bytes(bytes: [u8; 8]) -> Option<NzFloat> {
+        let key = u64::from_be_bytes(bytes);
+        let bits = if key >> 63 == 1 {
+            key & !(1 << 63)
+        } else {
+            !key
+        };
+        NzFloat::new(f64::from_bits(bits))
+    }
+
     /// Construct +1.0.
     #[inline]
-    pub fn one() -> NzFloat {
+    pub const fn one() -> NzFloat {
         unsafe { NzFloat::new_unchecked(1.0) }
     }
 
     /// Construct -1.0.
     #[inline]
-    pub fn neg_one() -> NzFloat {
+    pub const fn neg_one() -> NzFloat {
         unsafe { NzFloat::new_unchecked(-1.0) }
     }
 }
@@ -119,8 +638,9 @@ impl fmt::Debug for NzFloat {
 
 impl fmt::Display for NzFloat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Avoid printing -0; invariant ensures not possible
-        write!(f, "{}", self.0)
+        // Avoid printing -0; invariant ensures not possible.
+        // Forward so width/precision flags ({:.2}) behave as for f64.
+        fmt::Display::fmt(&self.0, f)
     }
 }
 
@@ -144,18 +664,50 @@ impl Ord for NzFloat {
     }
 }
 
+// Comparisons with raw f64 follow IEEE-754: NaN compares unequal and unordered.
+impl PartialEq<f64> for NzFloat {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+impl PartialEq<NzFloat> for f64 {
+    fn eq(&self, other: &NzFloat) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<f64> for NzFloat {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+impl PartialOrd<NzFloat> for f64 {
+    fn partial_cmp(&self, other: &NzFloat) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
 impl Hash for NzFloat {
+    #[cfg(not(feature = "stable-hash"))]
     fn hash<H: Hasher>(&self, state: &mut H) {
         // No NaN and no ±0.0 -> to_bits is stable
         self.0.to_bits().hash(state)
     }
+    #[cfg(feature = "stable-hash")]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_stable_bytes())
+    }
 }
 
 impl TryFrom<f64> for NzFloat {
-    type Error = NzfError;
+    type Error = NzfParseError;
     #[inline]
     fn try_from(v: f64) -> Result<Self, Self::Error> {
-        NzFloat::new(v).ok_or(NzfError::ZeroResult)
+        match NzFloat::new(v) {
+            Some(nz) => Ok(nz),
+            None if v.is_nan() => Err(NzfParseError::NaNInput(v.to_bits())),
+            None => Err(NzfParseError::ZeroInput(v.to_bits())),
+        }
     }
 }
 
@@ -165,3 +717,95 @@ impl From<NzFloat> for f64 {
         v.0
     }
 }
+
+/* ----- Operator traits (panic on zero/NaN result; use checked_* for fallible paths) ----- */
+
+/// Unwrap a checked result for an operator impl; shared with NzF32 and Strict<NzFloat>.
+#[inline]
+#[track_caller]
+pub(crate) fn unwrap_op<T>(r: Result<T, NzfError>, op: &str) -> T {
+    match r {
+        Ok(v) => v,
+        Err(NzfError::ZeroResult) => panic!("attempt to {op} with zero result"),
+        Err(NzfError::NotANumber) => panic!("attempt to {op} with NaN result"),
+        Err(NzfError::Infinite) => panic!("attempt to {op} with infinite result"),
+        Err(NzfError::Underflow) => panic!("attempt to {op} with subnormal result"),
+    }
+}
+
+impl Add for NzFloat {
+    type Output = NzFloat;
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: NzFloat) -> NzFloat {
+        unwrap_op(self.checked_add(rhs), "add")
+    }
+}
+
+impl Sub for NzFloat {
+    type Output = NzFloat;
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: NzFloat) -> NzFloat {
+        unwrap_op(self.checked_sub(rhs), "subtract")
+    }
+}
+
+impl Mul for NzFloat {
+    type Output = NzFloat;
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: NzFloat) -> NzFloat {
+        unwrap_op(self.checked_mul(rhs), "multiply")
+    }
+}
+
+impl Div for NzFloat {
+    type Output = NzFloat;
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: NzFloat) -> NzFloat {
+        unwrap_op(self.checked_div(rhs), "divide")
+    }
+}
+
+impl Neg for NzFloat {
+    type Output = NzFloat;
+    /// Total: negation never produces 0.0 or NaN.
+    #[inline]
+    fn neg(self) -> NzFloat {
+        NzFloat(-self.0)
+    }
+}
+
+impl AddAssign for NzFloat {
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: NzFloat) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for NzFloat {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: NzFloat) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for NzFloat {
+    #[inline]
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: NzFloat) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for NzFloat {
+    #[inline]
+    #[track_caller]
+    fn div_assign(&mut self, rhs: NzFloat) {
+        *self = *self / rhs;
+    }
+}
@@ -2,12 +2,19 @@
 //! Invariants:
 //! - Value is always non-zero (i64 != 0)
 //! - Arithmetic helpers return Result and never construct zero
+//!
 //! Design choices:
 //! - Backed by core::num::NonZeroI64 for niche optimization (zero-cost)
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt;
 use core::hash::{Hash, Hasher};
-use core::num::NonZeroI64;
+use core::num::{NonZeroI64, NonZeroU32, NonZeroU64};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::nzfloat::NzFloat;
+use crate::nzpositive::PzInt;
 
 /// Error type for nzint operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,92 +23,157 @@ pub enum NzError {
     ZeroResult,
     /// Integer division overflow (e.g., i64::MIN / -1).
     DivOverflow,
+    /// The value does not fit in the target width.
+    OutOfRange,
+    /// The exact result does not fit in i64 (e.g., checked_pow, checked_shl).
+    Overflow,
+    /// The value cannot be represented exactly in the target type.
+    Inexact,
+    /// The operation is undefined for this input (e.g., an even root of a negative value).
+    Domain,
+}
+
+impl fmt::Display for NzError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NzError::ZeroResult => "result is zero",
+            NzError::DivOverflow => "integer overflow in division or negation",
+            NzError::OutOfRange => "value out of range for the target type",
+            NzError::Overflow => "result does not fit in i64",
+            NzError::Inexact => "value cannot be represented exactly",
+            NzError::Domain => "operation is undefined for this input",
+        })
+    }
+}
+
+impl core::error::Error for NzError {}
+
+/// Report of every zero input found by `NzInt::try_collect_report`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroReport {
+    /// Total number of zero inputs.
+    pub count: usize,
+    /// Positions of the first `ZeroReport::MAX_INDICES` zero inputs, in order.
+    pub indices: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl ZeroReport {
+    /// Maximum number of indices recorded; `count` keeps counting past it.
+    pub const MAX_INDICES: usize = 64;
+
+    /// True if more zeros were found than `indices` records.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.count > self.indices.len()
+    }
 }
 
+/// repr(transparent): slices of i64 known to be non-zero can be viewed as slices of NzInt.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct NzInt(NonZeroI64);
 
+// Layout guarantees behind the slice views (validate_chunked, batch) and the C ABI;
+// Option<NzInt> uses the zero niche, so None is stored as 0.
+const _: () = {
+    assert!(size_of::<NzInt>() == size_of::<i64>());
+    assert!(align_of::<NzInt>() == align_of::<i64>());
+    assert!(size_of::<Option<NzInt>>() == size_of::<i64>());
+};
+
 impl NzInt {
     /// Create a new NzInt. Returns None if v == 0.
     #[inline]
-    pub fn new(v: i64) -> Option<Self> {
-        NonZeroI64::new(v).map(NzInt)
+    pub const fn new(v: i64) -> Option<Self> {
+        // Option::map is not const
+        match NonZeroI64::new(v) {
+            Some(nz) => Some(NzInt(nz)),
+            None => None,
+        }
+    }
+
+    /// Create a new NzInt, or `fallback` if v == 0.
+    #[inline]
+    pub const fn new_or(v: i64, fallback: NzInt) -> Self {
+        match NzInt::new(v) {
+            Some(x) => x,
+            None => fallback,
+        }
+    }
+
+    /// Create a new NzInt, snapping 0 to 1; total.
+    #[inline]
+    pub const fn new_snap(v: i64) -> Self {
+        NzInt::new_or(v, NzInt::one())
     }
 
     /// Create a new NzInt without checking. Caller must guarantee v != 0.
     /// # Safety
     /// Passing 0 is UB for NonZeroI64 and breaks invariants.
     #[inline]
-    pub unsafe fn new_unchecked(v: i64) -> Self {
-        NzInt(NonZeroI64::new_unchecked(v))
+    pub const unsafe fn new_unchecked(v: i64) -> Self {
+        NzInt(unsafe { NonZeroI64::new_unchecked(v) })
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Validate a whole batch in one pass.
+    /// Returns all values if none is zero; otherwise a ZeroReport with the zero count
+    /// and the (capped) indices of the zero inputs, not just the first one.
+    pub fn try_collect_report<I: IntoIterator<Item = i64>>(
+        iter: I,
+    ) -> Result<Vec<NzInt>, ZeroReport> {
+        let iter = iter.into_iter();
+        let mut values = Vec::with_capacity(iter.size_hint().0);
+        let mut report = ZeroReport {
+            count: 0,
+            indices: Vec::new(),
+        };
+        for (i, v) in iter.enumerate() {
+            match NzInt::new(v) {
+                Some(nz) if report.count == 0 => values.push(nz),
+                Some(_) => {}
+                None => {
+                    if report.indices.len() < ZeroReport::MAX_INDICES {
+                        report.indices.push(i);
+                    }
+                    report.count += 1;
+                }
+            }
+        }
+        if report.count == 0 {
+            Ok(values)
+        } else {
+            Err(report)
+        }
     }
 
     /// Get the inner i64.
     #[inline]
-    pub fn get(self) -> i64 {
+    pub const fn get(self) -> i64 {
         self.0.get()
     }
 
     /// Checked addition. Returns Err(ZeroResult) if the sum is zero.
+    /// Overflow wraps (OverflowPolicy::Wrapping); use add_with or Strict<NzInt> to reject it.
     #[inline]
     pub fn checked_add(self, rhs: NzInt) -> Result<NzInt, NzError> {
-        let a = self.get();
-        let b = rhs.get();
-        let (res, overflow) = a.overflowing_add(b);
-        if overflow {
-            // Overflow can never yield 0 for i64 unless wrapping hits 0 exactly.
-            // Guard anyway using the invariant below.
-            if res == 0 {
-                return Err(NzError::ZeroResult);
-            }
-            // Non-zero and overflowed -> still a valid i64; construct via NonZeroI64::new_unchecked.
-            return Ok(unsafe { NzInt::new_unchecked(res) });
-        }
-        if res == 0 {
-            Err(NzError::ZeroResult)
-        } else {
-            Ok(unsafe { NzInt::new_unchecked(res) })
-        }
+        // A single zero test on the wrapped result: NonZeroI64::new lowers to one compare,
+        // and wrapping can only produce 0 when the exact sum is a multiple of 2^64.
+        NzInt::new(self.get().wrapping_add(rhs.get())).ok_or(NzError::ZeroResult)
     }
 
     /// Checked subtraction. Returns Err(ZeroResult) if the difference is zero.
     #[inline]
     pub fn checked_sub(self, rhs: NzInt) -> Result<NzInt, NzError> {
-        let a = self.get();
-        let b = rhs.get();
-        let (res, overflow) = a.overflowing_sub(b);
-        if overflow {
-            if res == 0 {
-                return Err(NzError::ZeroResult);
-            }
-            return Ok(unsafe { NzInt::new_unchecked(res) });
-        }
-        if res == 0 {
-            Err(NzError::ZeroResult)
-        } else {
-            Ok(unsafe { NzInt::new_unchecked(res) })
-        }
+        NzInt::new(self.get().wrapping_sub(rhs.get())).ok_or(NzError::ZeroResult)
     }
 
-    /// Checked multiplication. Returns Err(ZeroResult) if the product is zero.
+    /// Checked multiplication. Returns Err(ZeroResult) if the (wrapped) product is zero.
     #[inline]
     pub fn checked_mul(self, rhs: NzInt) -> Result<NzInt, NzError> {
-        let a = self.get();
-        let b = rhs.get();
-        // If either factor is +/-1, product can be zero only if the other is 0 (which cannot happen).
-        // For general case use overflowing_mul and check result.
-        let (res, overflow) = a.overflowing_mul(b);
-        if overflow {
-            if res == 0 {
-                return Err(NzError::ZeroResult);
-            }
-            return Ok(unsafe { NzInt::new_unchecked(res) });
-        }
-        if res == 0 {
-            Err(NzError::ZeroResult)
-        } else {
-            Ok(unsafe { NzInt::new_unchecked(res) })
-        }
+        NzInt::new(self.get().wrapping_mul(rhs.get())).ok_or(NzError::ZeroResult)
     }
 
     /// Checked division (truncates toward zero).
@@ -110,19 +182,284 @@ impl NzInt {
     /// - Err(DivOverflow) if a == i64::MIN and b == -1 (overflow in two's complement).
     #[inline]
     pub fn checked_div(self, rhs: NzInt) -> Result<NzInt, NzError> {
+        // Divisor is guaranteed non-zero by invariant, so checked_div fails only for i64::MIN / -1.
+        let q = self
+            .get()
+            .checked_div(rhs.get())
+            .ok_or(NzError::DivOverflow)?;
+        NzInt::new(q).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked remainder (sign follows self, like `%`).
+    /// Returns:
+    /// - Err(ZeroResult) if rhs divides self exactly.
+    /// - Err(DivOverflow) if a == i64::MIN and b == -1.
+    #[inline]
+    pub fn checked_rem(self, rhs: NzInt) -> Result<NzInt, NzError> {
+        let r = self
+            .get()
+            .checked_rem(rhs.get())
+            .ok_or(NzError::DivOverflow)?;
+        NzInt::new(r).ok_or(NzError::ZeroResult)
+    }
+
+    /// Quotient and remainder (truncating, like `/` and `%`) in one division.
+    /// The remainder is None when rhs divides self exactly.
+    /// Returns:
+    /// - Err(ZeroResult) if the quotient is zero (|self| < |rhs|).
+    /// - Err(DivOverflow) if a == i64::MIN and b == -1.
+    #[inline]
+    pub fn checked_div_rem(self, rhs: NzInt) -> Result<(NzInt, Option<NzInt>), NzError> {
+        let (a, b) = (self.get(), rhs.get());
+        let q = a.checked_div(b).ok_or(NzError::DivOverflow)?;
+        let q = NzInt::new(q).ok_or(NzError::ZeroResult)?;
+        Ok((q, NzInt::new(a % b)))
+    }
+
+    /// |self - rhs| as an unsigned value (it can exceed i64::MAX). Err(ZeroResult) if self == rhs.
+    #[inline]
+    pub fn checked_abs_diff(self, rhs: NzInt) -> Result<NonZeroU64, NzError> {
+        NonZeroU64::new(self.get().abs_diff(rhs.get())).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked Euclidean division (the quotient q such that self = q * rhs + r, 0 <= r < |rhs|).
+    /// Returns:
+    /// - Err(ZeroResult) if the quotient is zero.
+    /// - Err(DivOverflow) if a == i64::MIN and b == -1.
+    #[inline]
+    pub fn checked_div_euclid(self, rhs: NzInt) -> Result<NzInt, NzError> {
+        let q = self
+            .get()
+            .checked_div_euclid(rhs.get())
+            .ok_or(NzError::DivOverflow)?;
+        NzInt::new(q).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked Euclidean remainder (always positive).
+    /// Returns:
+    /// - Err(ZeroResult) if rhs divides self exactly.
+    /// - Err(DivOverflow) if a == i64::MIN and b == -1.
+    #[inline]
+    pub fn checked_rem_euclid(self, rhs: NzInt) -> Result<NzInt, NzError> {
+        let r = self
+            .get()
+            .checked_rem_euclid(rhs.get())
+            .ok_or(NzError::DivOverflow)?;
+        NzInt::new(r).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked addition of a raw i64, which may be zero. Same rules as checked_add
+    /// (wrapping; Err(ZeroResult) only if the sum is zero), so `x + 0` is simply `x`.
+    #[inline]
+    pub fn checked_add_i64(self, rhs: i64) -> Result<NzInt, NzError> {
+        NzInt::new(self.get().wrapping_add(rhs)).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked subtraction of a raw i64, which may be zero. Same rules as checked_sub.
+    #[inline]
+    pub fn checked_sub_i64(self, rhs: i64) -> Result<NzInt, NzError> {
+        NzInt::new(self.get().wrapping_sub(rhs)).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked multiplication by a raw i64. Err(ZeroResult) if rhs == 0 or the wrapped
+    /// product is zero.
+    #[inline]
+    pub fn checked_mul_i64(self, rhs: i64) -> Result<NzInt, NzError> {
+        NzInt::new(self.get().wrapping_mul(rhs)).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked division by a raw i64. Err(Domain) if rhs == 0; otherwise as checked_div.
+    #[inline]
+    pub fn checked_div_i64(self, rhs: i64) -> Result<NzInt, NzError> {
+        self.checked_div(NzInt::new(rhs).ok_or(NzError::Domain)?)
+    }
+
+    /// True if rhs divides self exactly, i.e. when checked_rem would return Err(ZeroResult).
+    /// Total: i64::MIN is a multiple of -1.
+    #[inline]
+    pub fn is_multiple_of(self, rhs: NzInt) -> bool {
+        self.get().wrapping_rem(rhs.get()) == 0
+    }
+
+    /// Checked exponentiation. self^0 == 1.
+    /// Returns Err(Overflow) if the result does not fit in i64 (a power of non-zero is never zero).
+    #[inline]
+    pub fn checked_pow(self, exp: u32) -> Result<NzInt, NzError> {
+        let r = self.get().checked_pow(exp).ok_or(NzError::Overflow)?;
+        debug_assert!(r != 0);
+        Ok(unsafe { NzInt::new_unchecked(r) })
+    }
+
+    /// Checked left shift, i.e. multiplication by 2^n.
+    /// Returns Err(Overflow) if n >= 64 or any significant bit (including the sign) is shifted out.
+    #[inline]
+    pub fn checked_shl(self, n: u32) -> Result<NzInt, NzError> {
         let a = self.get();
-        let b = rhs.get();
-        // Divisor is guaranteed non-zero by invariant.
-        if a == i64::MIN && b == -1 {
-            // i64::MIN / -1 overflows
-            return Err(NzError::DivOverflow);
+        let r = a.checked_shl(n).ok_or(NzError::Overflow)?;
+        if r >> n != a {
+            return Err(NzError::Overflow);
+        }
+        Ok(unsafe { NzInt::new_unchecked(r) })
+    }
+
+    /// Checked arithmetic right shift, i.e. division by 2^n rounding toward -inf.
+    /// Returns:
+    /// - Err(ZeroResult) if all set bits of a positive value are shifted out.
+    /// - Err(Overflow) if n >= 64.
+    #[inline]
+    pub fn checked_shr(self, n: u32) -> Result<NzInt, NzError> {
+        let r = self.get().checked_shr(n).ok_or(NzError::Overflow)?;
+        NzInt::new(r).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked scaling by 10^exp (e.g., ms -> ns with exp = 6).
+    /// Returns Err(Overflow) if the exact result does not fit in i64.
+    #[inline]
+    pub fn checked_mul_pow10(self, exp: u32) -> Result<NzInt, NzError> {
+        let r = 10i64
+            .checked_pow(exp)
+            .and_then(|p| self.get().checked_mul(p))
+            .ok_or(NzError::Overflow)?;
+        Ok(unsafe { NzInt::new_unchecked(r) })
+    }
+
+    /// Checked division by 10^exp, truncating toward zero (e.g., cents -> dollars with exp = 2).
+    /// Returns Err(ZeroResult) if |self| < 10^exp.
+    #[inline]
+    pub fn checked_div_pow10(self, exp: u32) -> Result<NzInt, NzError> {
+        // 10^19 exceeds every |i64|, so an unrepresentable divisor means a zero quotient.
+        let q = 10i64.checked_pow(exp).map_or(0, |p| self.get() / p);
+        NzInt::new(q).ok_or(NzError::ZeroResult)
+    }
+
+    /// Checked scaling by 2^exp. Same as checked_shl: Err(Overflow) if the result does not fit.
+    #[inline]
+    pub fn checked_mul_pow2(self, exp: u32) -> Result<NzInt, NzError> {
+        self.checked_shl(exp)
+    }
+
+    /// Checked division by 2^exp, truncating toward zero (unlike checked_shr, which floors).
+    /// Returns Err(ZeroResult) if |self| < 2^exp.
+    #[inline]
+    pub fn checked_div_pow2(self, exp: u32) -> Result<NzInt, NzError> {
+        let q = 1i64
+            .checked_shl(exp)
+            .filter(|p| *p > 0)
+            .map_or(0, |p| self.get() / p);
+        // i64::MIN / 2^63 == -1 is the one quotient whose divisor does not fit in i64.
+        let q = if exp == 63 && self.get() == i64::MIN {
+            -1
+        } else {
+            q
+        };
+        NzInt::new(q).ok_or(NzError::ZeroResult)
+    }
+
+    /// Integer n-th root, truncated toward zero, with a flag telling whether it is exact
+    /// (root^n == self). Odd roots keep the sign; the root of a non-zero value is never zero.
+    /// Returns Err(Domain) for n == 0 or an even root of a negative value.
+    pub fn checked_nth_root(self, n: u32) -> Result<(NzInt, bool), NzError> {
+        let a = self.get();
+        if n == 0 || (a < 0 && n.is_multiple_of(2)) {
+            return Err(NzError::Domain);
+        }
+        let m = self.abs_unsigned().get();
+        // Binary search for the largest r with r^n <= m; m < 2^bits bounds r below 2^(bits/n + 1).
+        let bits = 64 - m.leading_zeros();
+        let (mut lo, mut hi) = (1u64, 1u64 << (bits / n + 1).min(63));
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if mid.checked_pow(n).is_some_and(|p| p <= m) {
+                lo = mid
+            } else {
+                hi = mid - 1
+            }
         }
-        let q = a / b;
-        if q == 0 {
-            Err(NzError::ZeroResult)
+        let r = lo;
+        let exact = r.pow(n) == m;
+        // r <= 2^63, and only i64::MIN with n == 1 reaches 2^63, which fits once negated.
+        let root = if a < 0 {
+            (r as i64).wrapping_neg()
         } else {
-            Ok(unsafe { NzInt::new_unchecked(q) })
+            r as i64
+        };
+        Ok((unsafe { NzInt::new_unchecked(root) }, exact))
+    }
+
+    /// Greatest common divisor of |self| and |rhs|; total, since both are non-zero.
+    /// Unsigned because gcd(i64::MIN, i64::MIN) is 2^63.
+    #[inline]
+    pub fn gcd(self, rhs: NzInt) -> NonZeroU64 {
+        let (mut a, mut b) = (self.abs_unsigned().get(), rhs.abs_unsigned().get());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        // a started non-zero and Euclid never reduces the gcd to 0.
+        unsafe { NonZeroU64::new_unchecked(a) }
+    }
+
+    /// Least common multiple of |self| and |rhs| (positive). Returns Err(Overflow) if it exceeds i64::MAX.
+    #[inline]
+    pub fn checked_lcm(self, rhs: NzInt) -> Result<NzInt, NzError> {
+        let a = self.abs_unsigned().get() / self.gcd(rhs).get();
+        let l = a
+            .checked_mul(rhs.abs_unsigned().get())
+            .and_then(|l| i64::try_from(l).ok());
+        // A product of non-zero factors is non-zero.
+        l.map(|l| unsafe { NzInt::new_unchecked(l) })
+            .ok_or(NzError::Overflow)
+    }
+
+    /// Modular inverse: x with self * x ≡ 1 (mod modulus), reduced like NzBigInt::modpow
+    /// (0 < x < |modulus|, negated into (modulus, 0) for a negative modulus).
+    /// Returns Err(Domain) if self and modulus are not coprime, Err(ZeroResult) for modulus ±1
+    /// (where every residue is 0).
+    pub fn mod_inverse(self, modulus: NzInt) -> Result<NzInt, NzError> {
+        let m = modulus.abs_unsigned().get() as i128;
+        // Extended Euclid on (self mod m, m), tracking the coefficient of self.
+        let (mut r0, mut r1) = ((self.get() as i128).rem_euclid(m), m);
+        let (mut s0, mut s1) = (1i128, 0i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (s0, s1) = (s1, s0 - q * s1);
         }
+        if r0 != 1 {
+            return Err(NzError::Domain);
+        }
+        Self::floor_residue(s0.rem_euclid(m) as u64, modulus)
+    }
+
+    /// Modular exponentiation self^exp mod modulus, reduced like NzBigInt::modpow
+    /// (the result has the sign of modulus). Returns Err(ZeroResult) if modulus divides self^exp.
+    pub fn checked_mod_pow(self, exp: u64, modulus: NzInt) -> Result<NzInt, NzError> {
+        let m = modulus.abs_unsigned().get() as u128;
+        let mut base = (self.get() as i128).rem_euclid(m as i128) as u128;
+        let (mut e, mut acc) = (exp, 1 % m);
+        // Operands stay below m <= 2^63, so products fit in u128.
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc * base % m;
+            }
+            base = base * base % m;
+            e >>= 1;
+        }
+        Self::floor_residue(acc as u64, modulus)
+    }
+
+    /// Map a residue r in [0, |modulus|) to mod_floor form; Err(ZeroResult) for r == 0.
+    #[inline]
+    fn floor_residue(r: u64, modulus: NzInt) -> Result<NzInt, NzError> {
+        if r == 0 {
+            return Err(NzError::ZeroResult);
+        }
+        // 0 < r < |modulus| <= 2^63, so both r and r - |modulus| fit in i64.
+        let v = if modulus.get() > 0 {
+            r as i64
+        } else {
+            (r as i128 - modulus.abs_unsigned().get() as i128) as i64
+        };
+        Ok(unsafe { NzInt::new_unchecked(v) })
     }
 
     /// Checked negation. Returns Err(ZeroResult) if result would be zero (impossible for nzint).
@@ -150,9 +487,185 @@ impl NzInt {
         Ok(unsafe { NzInt::new_unchecked(r) })
     }
 
+    /// Saturating addition; never fails.
+    /// Overflow clamps to i64::MAX / i64::MIN. An exact zero sum (self == -rhs)
+    /// snaps to 1 if self is positive, -1 if self is negative.
+    #[inline]
+    pub fn saturating_add(self, rhs: NzInt) -> NzInt {
+        NzInt::new(self.get().saturating_add(rhs.get())).unwrap_or(self.signum())
+    }
+
+    /// Saturating subtraction; never fails.
+    /// Overflow clamps to i64::MAX / i64::MIN. An exact zero difference (self == rhs)
+    /// snaps to 1 if self is positive, -1 if self is negative.
+    #[inline]
+    pub fn saturating_sub(self, rhs: NzInt) -> NzInt {
+        NzInt::new(self.get().saturating_sub(rhs.get())).unwrap_or(self.signum())
+    }
+
+    /// Saturating multiplication; never fails.
+    /// Overflow clamps to i64::MAX / i64::MIN. A product of non-zero values is never zero.
+    #[inline]
+    pub fn saturating_mul(self, rhs: NzInt) -> NzInt {
+        let r = self.get().saturating_mul(rhs.get());
+        debug_assert!(r != 0);
+        unsafe { NzInt::new_unchecked(r) }
+    }
+
+    /// Saturating division (truncates toward zero); never fails.
+    /// i64::MIN / -1 clamps to i64::MAX. A quotient that truncates to zero (|self| < |rhs|)
+    /// snaps to 1 if the operands have the same sign, -1 otherwise.
+    #[inline]
+    pub fn saturating_div(self, rhs: NzInt) -> NzInt {
+        let q = self.get().saturating_div(rhs.get());
+        NzInt::new(q).unwrap_or(if (self.get() > 0) == (rhs.get() > 0) {
+            NzInt::one()
+        } else {
+            NzInt::neg_one()
+        })
+    }
+
+    /// Wrapping addition modulo 2^64 that skips zero; never fails.
+    /// A sum that wraps or cancels to exactly 0 steps past zero in the direction of rhs
+    /// (+1 if rhs > 0, -1 if rhs < 0), as a counter that never stops at 0 would.
+    /// The flag is true if the result is not the exact sum (overflowed or skipped zero).
+    #[inline]
+    pub fn wrapping_add(self, rhs: NzInt) -> (NzInt, bool) {
+        let (r, overflow) = self.get().overflowing_add(rhs.get());
+        match NzInt::new(r) {
+            Some(v) => (v, overflow),
+            None => (rhs.signum(), true),
+        }
+    }
+
+    /// Wrapping subtraction modulo 2^64 that skips zero; never fails.
+    /// A difference that wraps or cancels to exactly 0 steps past zero in the direction
+    /// of -rhs (-1 if rhs > 0, +1 if rhs < 0).
+    /// The flag is true if the result is not the exact difference (overflowed or skipped zero).
+    #[inline]
+    pub fn wrapping_sub(self, rhs: NzInt) -> (NzInt, bool) {
+        let (r, overflow) = self.get().overflowing_sub(rhs.get());
+        match NzInt::new(r) {
+            Some(v) => (v, overflow),
+            None => (
+                if rhs.get() > 0 {
+                    NzInt::neg_one()
+                } else {
+                    NzInt::one()
+                },
+                true,
+            ),
+        }
+    }
+
+    /// Wrapping multiplication modulo 2^64 that skips zero; never fails.
+    /// A product that wraps to exactly 0 (e.g., 2^32 * 2^32) becomes the sign of the
+    /// exact product (+1 if the operands have the same sign, -1 otherwise).
+    /// The flag is true if the result is not the exact product.
+    #[inline]
+    pub fn wrapping_mul(self, rhs: NzInt) -> (NzInt, bool) {
+        let (r, overflow) = self.get().overflowing_mul(rhs.get());
+        match NzInt::new(r) {
+            Some(v) => (v, overflow),
+            None => (
+                if (self.get() > 0) == (rhs.get() > 0) {
+                    NzInt::one()
+                } else {
+                    NzInt::neg_one()
+                },
+                true,
+            ),
+        }
+    }
+
+    /// Wrapping negation; never fails. -i64::MIN wraps to i64::MIN (never zero).
+    /// The flag is true only for i64::MIN.
+    #[inline]
+    pub fn wrapping_neg(self) -> (NzInt, bool) {
+        let (r, overflow) = self.get().overflowing_neg();
+        debug_assert!(r != 0);
+        (unsafe { NzInt::new_unchecked(r) }, overflow)
+    }
+
+    /// Convert to NzFloat, rounding to nearest for |v| > 2^53. Total: a non-zero i64 never rounds to 0.0.
+    #[inline]
+    pub fn to_float(self) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(self.get() as f64) }
+    }
+
+    /// Convert to NzFloat only if the value is exactly representable in f64.
+    /// Returns Err(Inexact) otherwise (e.g., 2^53 + 1).
+    #[inline]
+    pub fn try_to_float_exact(self) -> Result<NzFloat, NzError> {
+        let a = self.get();
+        let f = a as f64;
+        // 2^63 is out of i64 range but `f as i64` saturates back to i64::MAX, so test it first.
+        if f < 9_223_372_036_854_775_808.0 && f as i64 == a {
+            Ok(unsafe { NzFloat::new_unchecked(f) })
+        } else {
+            Err(NzError::Inexact)
+        }
+    }
+
+    /// True if the value survives an i64 -> f64 -> i64 round trip (|v| <= 2^53, or a
+    /// larger value with enough trailing zero bits).
+    #[inline]
+    pub fn fits_in_f64(self) -> bool {
+        self.try_to_float_exact().is_ok()
+    }
+
+    /// Stable byte encoding: the value as 8 bytes of little-endian two's complement.
+    /// This definition is fixed across platforms and releases; with the `stable-hash`
+    /// feature, `Hash` writes exactly these bytes.
+    #[inline]
+    pub fn to_stable_bytes(self) -> [u8; 8] {
+        self.get().to_le_bytes()
+    }
+
+    /// Sort-key encoding: big-endian with the sign bit flipped, so comparing the bytes
+    /// lexicographically (memcmp) orders them like the values. Usable directly as a
+    /// key in byte-ordered stores.
+    #[inline]
+    pub const fn to_order_preserving_bytes(self) -> [u8; 8] {
+        ((self.get() as u64) ^ (1 << 63)).to_be_bytes()
+    }
+
+    /// Inverse of to_order_preserving_bytes. Returns None for the encoding of 0.
+    #[inline]
+    pub const fn from_order_preserving_bytes(bytes: [u8; 8]) -> Option<NzInt> {
+        NzInt::new((u64::from_be_bytes(bytes) ^ (1 << 63)) as i64)
+    }
+
+    /// Magnitude as NonZeroU64. Total, unlike checked_abs: |i64::MIN| == 2^63 fits in u64.
+    /// Prefer this in magnitude-based algorithms to avoid the DivOverflow path.
+    #[inline]
+    pub fn abs_unsigned(self) -> NonZeroU64 {
+        self.0.unsigned_abs()
+    }
+
+    /// Clamp |self| into [|min|, |max|], keeping the sign of self. Total: |min| >= 1, and a
+    /// positive result above i64::MAX (bound i64::MIN) saturates to i64::MAX.
+    /// The bounds' own signs are ignored.
+    /// # Panics
+    /// Panics if |min| > |max|, like Ord::clamp.
+    #[inline]
+    pub fn clamp_magnitude(self, min: NzInt, max: NzInt) -> NzInt {
+        let m = self
+            .abs_unsigned()
+            .clamp(min.abs_unsigned(), max.abs_unsigned())
+            .get();
+        // m <= 2^63, so the negative side always fits (2^63 wraps onto i64::MIN).
+        let v = if self.get() < 0 {
+            (m as i64).wrapping_neg()
+        } else {
+            m.min(i64::MAX as u64) as i64
+        };
+        unsafe { NzInt::new_unchecked(v) }
+    }
+
     /// Sign of the value: +1 for positive, -1 for negative (as NzInt).
     #[inline]
-    pub fn signum(self) -> NzInt {
+    pub const fn signum(self) -> NzInt {
         // a != 0 always holds; (a > 0) as i64 yields 0/1, so avoid that.
         if self.get() > 0 {
             unsafe { NzInt::new_unchecked(1) }
@@ -162,6 +675,64 @@ impl NzInt {
     }
 }
 
+/* ----- Bit-level queries ----- */
+
+impl NzInt {
+    /// Leading zero bits of the two's-complement representation (0 for negatives); < 64.
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    /// Trailing zero bits; < 64 since some bit is set.
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        self.0.trailing_zeros()
+    }
+
+    /// Set bits of the two's-complement representation; never zero.
+    #[inline]
+    pub const fn count_ones(self) -> NonZeroU32 {
+        self.0.count_ones()
+    }
+
+    /// floor(log2(self)). Returns Err(Domain) for negatives; see PzInt::ilog2 for the total form.
+    #[inline]
+    pub fn checked_ilog2(self) -> Result<u32, NzError> {
+        self.positive().map(PzInt::ilog2)
+    }
+
+    /// floor(log10(self)). Returns Err(Domain) for negatives; see PzInt::ilog10 for the total form.
+    #[inline]
+    pub fn checked_ilog10(self) -> Result<u32, NzError> {
+        self.positive().map(PzInt::ilog10)
+    }
+
+    /// True if self is 2^k (never for negatives).
+    #[inline]
+    pub const fn is_power_of_two(self) -> bool {
+        self.get() > 0 && (self.get() as u64).is_power_of_two()
+    }
+
+    /// Smallest power of two >= self.
+    /// Returns Err(Domain) for negatives, Err(Overflow) above 2^62.
+    #[inline]
+    pub fn checked_next_power_of_two(self) -> Result<PzInt, NzError> {
+        self.positive()?.checked_next_power_of_two()
+    }
+
+    /// Largest power of two <= self. Returns Err(Domain) for negatives.
+    #[inline]
+    pub fn checked_prev_power_of_two(self) -> Result<PzInt, NzError> {
+        self.positive().map(PzInt::prev_power_of_two)
+    }
+
+    #[inline]
+    fn positive(self) -> Result<PzInt, NzError> {
+        PzInt::new(self.get()).ok_or(NzError::Domain)
+    }
+}
+
 /* ----- Trait impls (Copy/Clone/Eq/Ord/Hash/Display/Debug/TryFrom/From) ----- */
 
 impl fmt::Debug for NzInt {
@@ -194,10 +765,37 @@ impl Ord for NzInt {
     }
 }
 
+impl PartialEq<i64> for NzInt {
+    fn eq(&self, other: &i64) -> bool {
+        self.get() == *other
+    }
+}
+impl PartialEq<NzInt> for i64 {
+    fn eq(&self, other: &NzInt) -> bool {
+        *self == other.get()
+    }
+}
+
+impl PartialOrd<i64> for NzInt {
+    fn partial_cmp(&self, other: &i64) -> Option<core::cmp::Ordering> {
+        Some(self.get().cmp(other))
+    }
+}
+impl PartialOrd<NzInt> for i64 {
+    fn partial_cmp(&self, other: &NzInt) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(&other.get()))
+    }
+}
+
 impl Hash for NzInt {
+    #[cfg(not(feature = "stable-hash"))]
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.get().hash(state)
     }
+    #[cfg(feature = "stable-hash")]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_stable_bytes())
+    }
 }
 
 impl From<NonZeroI64> for NzInt {
@@ -207,6 +805,13 @@ impl From<NonZeroI64> for NzInt {
     }
 }
 
+impl From<NzInt> for NonZeroI64 {
+    #[inline]
+    fn from(v: NzInt) -> Self {
+        v.0
+    }
+}
+
 impl TryFrom<i64> for NzInt {
     type Error = NzError;
     #[inline]
@@ -215,17 +820,107 @@ impl TryFrom<i64> for NzInt {
     }
 }
 
+/* ----- Operator traits (panic on zero result, like std ops panic on overflow) ----- */
+
+/// Unwrap a checked result for an operator impl; shared with Strict<NzInt>.
+#[inline]
+#[track_caller]
+pub(crate) fn unwrap_op(r: Result<NzInt, NzError>, op: &str) -> NzInt {
+    match r {
+        Ok(v) => v,
+        Err(NzError::ZeroResult) => panic!("attempt to {op} with zero result"),
+        Err(_) => panic!("attempt to {op} with overflow"),
+    }
+}
+
+impl Add for NzInt {
+    type Output = NzInt;
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: NzInt) -> NzInt {
+        unwrap_op(self.checked_add(rhs), "add")
+    }
+}
+
+impl Sub for NzInt {
+    type Output = NzInt;
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: NzInt) -> NzInt {
+        unwrap_op(self.checked_sub(rhs), "subtract")
+    }
+}
+
+impl Mul for NzInt {
+    type Output = NzInt;
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: NzInt) -> NzInt {
+        unwrap_op(self.checked_mul(rhs), "multiply")
+    }
+}
+
+impl Div for NzInt {
+    type Output = NzInt;
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: NzInt) -> NzInt {
+        unwrap_op(self.checked_div(rhs), "divide")
+    }
+}
+
+impl Neg for NzInt {
+    type Output = NzInt;
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> NzInt {
+        unwrap_op(self.checked_neg(), "negate")
+    }
+}
+
+impl AddAssign for NzInt {
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: NzInt) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for NzInt {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: NzInt) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for NzInt {
+    #[inline]
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: NzInt) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for NzInt {
+    #[inline]
+    #[track_caller]
+    fn div_assign(&mut self, rhs: NzInt) {
+        *self = *self / rhs;
+    }
+}
+
 /* ----- Optional convenience constructors for small non-zero constants ----- */
 
 impl NzInt {
     /// Construct +1.
     #[inline]
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         unsafe { NzInt::new_unchecked(1) }
     }
     /// Construct -1.
     #[inline]
-    pub fn neg_one() -> Self {
+    pub const fn neg_one() -> Self {
         unsafe { NzInt::new_unchecked(-1) }
     }
 }
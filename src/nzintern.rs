@@ -0,0 +1,79 @@
+//! nzintern: Interning pool mapping NzInt constants to 4-byte handles
+//! Invariants:
+//! - Handles are never zero (backed by NonZeroU32), so Option<NzHandle> is also 4 bytes
+//! - Each distinct value is stored once; interning the same value returns the same handle
+//!
+//! API:
+//! - NzInterner::new(), intern(v) -> NzHandle, lookup(v) -> Option<NzHandle>
+//! - resolve(handle) -> Option<NzInt> (O(1) index), len()/is_empty()
+
+use core::num::NonZeroU32;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+use crate::nzint::NzInt;
+
+/// Handle to an interned NzInt. Handle n refers to the n-th distinct value (1-based).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NzHandle(NonZeroU32);
+
+impl NzHandle {
+    /// Get the raw handle value (never 0).
+    #[inline]
+    pub fn get(self) -> u32 {
+        self.0.get()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NzInterner {
+    values: Vec<NzInt>,
+    handles: HashMap<NzInt, NzHandle>,
+}
+
+impl NzInterner {
+    /// Create an empty pool.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern a value and return its handle.
+    /// # Panics
+    /// Panics if more than u32::MAX distinct values are interned.
+    pub fn intern(&mut self, v: NzInt) -> NzHandle {
+        if let Some(&h) = self.handles.get(&v) {
+            return h;
+        }
+        let n = u32::try_from(self.values.len() + 1).expect("NzInterner: handle space exhausted");
+        // n >= 1, so the handle is never zero.
+        let h = NzHandle(unsafe { NonZeroU32::new_unchecked(n) });
+        self.values.push(v);
+        self.handles.insert(v, h);
+        h
+    }
+
+    /// Handle of an already interned value, if any.
+    #[inline]
+    pub fn lookup(&self, v: NzInt) -> Option<NzHandle> {
+        self.handles.get(&v).copied()
+    }
+
+    /// Value behind a handle. Returns None for handles from another pool that are out of range.
+    #[inline]
+    pub fn resolve(&self, h: NzHandle) -> Option<NzInt> {
+        self.values.get(h.get() as usize - 1).copied()
+    }
+
+    /// Number of distinct values interned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// True if nothing has been interned yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
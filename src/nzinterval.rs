@@ -0,0 +1,215 @@
+//! nzinterval: Closed interval [lo, hi] with non-zero NzFloat endpoints
+//! Invariants:
+//! - lo <= hi; neither endpoint is ±0.0 or NaN (±inf allowed)
+//! - The interval may straddle zero (lo < 0 < hi); contains_zero() reports it
+//!
+//! API:
+//! - NzInterval::new(lo, hi) -> Option<Self>, point(x), lo(), hi(), contains(x), contains_zero()
+//! - checked_add/sub/mul -> Result<Self, NzIntervalError> (Err only for NaN, from inf - inf)
+//! - checked_div: Err(DivisorContainsZero) when the divisor straddles zero
+//! - div_split: extended division; a straddling divisor yields two intervals instead of an error
+//!
+//! Design choices:
+//! - Results are widened outward by one ulp per endpoint, so they enclose the exact result
+//!   despite round-to-nearest; an endpoint that would round to 0.0 steps past it to ±5e-324,
+//!   which makes a result that touches zero straddle it
+//! - The zero check moves from values to intervals: a scalar op fails when its result is zero,
+//!   an interval op reports that its result might be, and only division refuses to continue
+
+use core::fmt;
+
+use crate::nzfloat::NzFloat;
+
+/// Error type for interval operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NzIntervalError {
+    /// The divisor interval contains zero; see div_split.
+    DivisorContainsZero,
+    /// An endpoint computation produced NaN (e.g., [inf, inf] - [inf, inf]).
+    NotANumber,
+}
+
+impl fmt::Display for NzIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NzIntervalError::DivisorContainsZero => "divisor interval contains zero",
+            NzIntervalError::NotANumber => "interval endpoint is NaN",
+        })
+    }
+}
+
+impl core::error::Error for NzIntervalError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NzInterval {
+    lo: NzFloat,
+    hi: NzFloat,
+}
+
+impl NzInterval {
+    /// [lo, hi]. Returns None if lo > hi.
+    #[inline]
+    pub fn new(lo: NzFloat, hi: NzFloat) -> Option<Self> {
+        if lo <= hi {
+            Some(NzInterval { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// The degenerate interval [x, x].
+    #[inline]
+    pub fn point(x: NzFloat) -> Self {
+        NzInterval { lo: x, hi: x }
+    }
+
+    /// The whole real line [-inf, +inf].
+    #[inline]
+    pub fn entire() -> Self {
+        unsafe { NzInterval::from_raw(f64::NEG_INFINITY, f64::INFINITY) }
+    }
+
+    /// Lower endpoint.
+    #[inline]
+    pub fn lo(self) -> NzFloat {
+        self.lo
+    }
+
+    /// Upper endpoint.
+    #[inline]
+    pub fn hi(self) -> NzFloat {
+        self.hi
+    }
+
+    /// True if lo <= x <= hi.
+    #[inline]
+    pub fn contains(self, x: f64) -> bool {
+        self.lo.get() <= x && x <= self.hi.get()
+    }
+
+    /// True if the interval straddles zero (lo < 0 < hi).
+    #[inline]
+    pub fn contains_zero(self) -> bool {
+        self.lo.get() < 0.0 && self.hi.get() > 0.0
+    }
+
+    /// Enclosure of { x + y }.
+    #[inline]
+    pub fn checked_add(self, rhs: NzInterval) -> Result<NzInterval, NzIntervalError> {
+        Self::outward(self.lo.get() + rhs.lo.get(), self.hi.get() + rhs.hi.get())
+    }
+
+    /// Enclosure of { x - y }.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzInterval) -> Result<NzInterval, NzIntervalError> {
+        Self::outward(self.lo.get() - rhs.hi.get(), self.hi.get() - rhs.lo.get())
+    }
+
+    /// Enclosure of { x * y }.
+    #[inline]
+    pub fn checked_mul(self, rhs: NzInterval) -> Result<NzInterval, NzIntervalError> {
+        let (a, b, c, d) = (self.lo.get(), self.hi.get(), rhs.lo.get(), rhs.hi.get());
+        // Endpoints are non-zero, so no 0 * inf NaN can occur here.
+        Self::hull([a * c, a * d, b * c, b * d])
+    }
+
+    /// Enclosure of { x / y }. Err(DivisorContainsZero) if rhs straddles zero.
+    #[inline]
+    pub fn checked_div(self, rhs: NzInterval) -> Result<NzInterval, NzIntervalError> {
+        if rhs.contains_zero() {
+            return Err(NzIntervalError::DivisorContainsZero);
+        }
+        let (a, b, c, d) = (self.lo.get(), self.hi.get(), rhs.lo.get(), rhs.hi.get());
+        Self::hull([a / c, a / d, b / c, b / d])
+    }
+
+    /// Extended division. A divisor that straddles zero splits into [c, 0) and (0, d]:
+    /// - zero-free dividend: two intervals, (negative part, positive part), each reaching ±inf
+    /// - dividend straddling zero: the quotients cover every real, so (entire(), None)
+    ///
+    /// A zero-free divisor gives (checked_div result, None).
+    pub fn div_split(
+        self,
+        rhs: NzInterval,
+    ) -> Result<(NzInterval, Option<NzInterval>), NzIntervalError> {
+        if !rhs.contains_zero() {
+            return self.checked_div(rhs).map(|q| (q, None));
+        }
+        if self.contains_zero() {
+            return Ok((NzInterval::entire(), None));
+        }
+        let (c, d) = (rhs.lo.get(), rhs.hi.get());
+        // The quotient of largest magnitude divides by the smallest-magnitude dividend.
+        let (neg, pos) = if self.lo.get() > 0.0 {
+            let a = self.lo.get();
+            (
+                Self::outward(f64::NEG_INFINITY, a / c)?,
+                Self::outward(a / d, f64::INFINITY)?,
+            )
+        } else {
+            let b = self.hi.get();
+            (
+                Self::outward(f64::NEG_INFINITY, b / d)?,
+                Self::outward(b / c, f64::INFINITY)?,
+            )
+        };
+        Ok((neg, Some(pos)))
+    }
+
+    /// Smallest interval holding all four candidates, widened outward.
+    #[inline]
+    fn hull(v: [f64; 4]) -> Result<NzInterval, NzIntervalError> {
+        if v.iter().any(|x| x.is_nan()) {
+            return Err(NzIntervalError::NotANumber);
+        }
+        let lo = v.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = v.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Self::outward(lo, hi)
+    }
+
+    /// Widen [lo, hi] by one ulp each way, stepping over 0.0.
+    #[inline]
+    fn outward(lo: f64, hi: f64) -> Result<NzInterval, NzIntervalError> {
+        if lo.is_nan() || hi.is_nan() {
+            return Err(NzIntervalError::NotANumber);
+        }
+        let lo = lo.next_down();
+        let lo = if lo == 0.0 { -f64::from_bits(1) } else { lo };
+        let hi = hi.next_up();
+        let hi = if hi == 0.0 { f64::from_bits(1) } else { hi };
+        // Non-NaN, non-zero, and lo <= hi since the inputs were ordered.
+        Ok(unsafe { NzInterval::from_raw(lo, hi) })
+    }
+
+    /// # Safety
+    /// lo <= hi, both non-zero and non-NaN.
+    #[inline]
+    unsafe fn from_raw(lo: f64, hi: f64) -> NzInterval {
+        debug_assert!(lo <= hi);
+        unsafe {
+            NzInterval {
+                lo: NzFloat::new_unchecked(lo),
+                hi: NzFloat::new_unchecked(hi),
+            }
+        }
+    }
+}
+
+impl From<NzFloat> for NzInterval {
+    #[inline]
+    fn from(x: NzFloat) -> Self {
+        NzInterval::point(x)
+    }
+}
+
+impl fmt::Debug for NzInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NzInterval[{:?}, {:?}]", self.lo.get(), self.hi.get())
+    }
+}
+
+impl fmt::Display for NzInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
@@ -0,0 +1,138 @@
+//! nziter: Folding iterators of non-zero values
+//! API:
+//! - Product for NzInt/NzFloat (and references): panics like `*` when a step yields zero
+//! - NzIteratorExt::try_sum()/try_product() -> Result<T, NzStepError<E>>
+//!
+//! Design choices:
+//! - No Sum impl: sums of non-zero values hit zero routinely (1 + -1), so only try_sum exists
+//! - NzStepError records which item broke the running total, not just why
+
+use core::fmt;
+use core::iter::Product;
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+
+/// A checked fold failed at a specific item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NzStepError<E> {
+    /// 0-based index of the item whose addition/multiplication failed
+    /// (0 only when the iterator was empty and its sum is zero).
+    pub index: usize,
+    /// What went wrong at that step.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for NzStepError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at item {}", self.error, self.index)
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for NzStepError<E> {}
+
+/// Element types try_sum/try_product can fold.
+pub trait NzAccumulate: Sized {
+    type Error;
+    /// Error reported for an empty sum (which is zero).
+    const EMPTY_SUM: Self::Error;
+    fn one() -> Self;
+    fn checked_add(self, rhs: Self) -> Result<Self, Self::Error>;
+    fn checked_mul(self, rhs: Self) -> Result<Self, Self::Error>;
+}
+
+impl NzAccumulate for NzInt {
+    type Error = NzError;
+    const EMPTY_SUM: NzError = NzError::ZeroResult;
+    #[inline]
+    fn one() -> Self {
+        NzInt::one()
+    }
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Result<Self, NzError> {
+        NzInt::checked_add(self, rhs)
+    }
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Result<Self, NzError> {
+        NzInt::checked_mul(self, rhs)
+    }
+}
+
+impl NzAccumulate for NzFloat {
+    type Error = NzfError;
+    const EMPTY_SUM: NzfError = NzfError::ZeroResult;
+    #[inline]
+    fn one() -> Self {
+        NzFloat::one()
+    }
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Result<Self, NzfError> {
+        NzFloat::checked_add(self, rhs)
+    }
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Result<Self, NzfError> {
+        NzFloat::checked_mul(self, rhs)
+    }
+}
+
+/// Checked folds for any iterator of NzInt or NzFloat.
+pub trait NzIteratorExt: Iterator {
+    /// Sum all items with the element type's checked_add, stopping at the first item that
+    /// makes the running total zero. An empty iterator yields Err with index 0 and the zero error.
+    fn try_sum(mut self) -> Result<Self::Item, NzStepError<<Self::Item as NzAccumulate>::Error>>
+    where
+        Self: Sized,
+        Self::Item: NzAccumulate,
+    {
+        let mut acc = self.next().ok_or(NzStepError {
+            index: 0,
+            error: Self::Item::EMPTY_SUM,
+        })?;
+        for (i, x) in self.enumerate() {
+            acc = acc.checked_add(x).map_err(|error| NzStepError {
+                index: i + 1,
+                error,
+            })?;
+        }
+        Ok(acc)
+    }
+
+    /// Multiply all items with the element type's checked_mul, stopping at the first zero
+    /// product (NzInt wrapping onto 0, NzFloat underflow). An empty iterator yields one.
+    fn try_product(self) -> Result<Self::Item, NzStepError<<Self::Item as NzAccumulate>::Error>>
+    where
+        Self: Sized,
+        Self::Item: NzAccumulate,
+    {
+        let mut acc = Self::Item::one();
+        for (index, x) in self.enumerate() {
+            acc = acc
+                .checked_mul(x)
+                .map_err(|error| NzStepError { index, error })?;
+        }
+        Ok(acc)
+    }
+}
+
+impl<I: Iterator> NzIteratorExt for I {}
+
+/* ----- Product impls (panic on failure, like the operators) ----- */
+
+macro_rules! nz_product {
+    ($t:ty) => {
+        impl Product for $t {
+            fn product<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold(<$t>::one(), |a, b| a * b)
+            }
+        }
+
+        impl<'a> Product<&'a $t> for $t {
+            fn product<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.fold(<$t>::one(), |a, b| a * *b)
+            }
+        }
+    };
+}
+
+nz_product!(NzInt);
+nz_product!(NzFloat);
@@ -0,0 +1,176 @@
+//! nzlog: Non-zero real number stored in the log domain as (sign, ln|x|)
+//! Invariants:
+//! - ln|x| is always finite, so the represented value is never 0, inf, or NaN
+//! - Multiplication/division are addition/subtraction of logs and are total:
+//!   a log sum that would leave the f64 range saturates at ±f64::MAX
+//!
+//! API:
+//! - NzLog::from_parts(sign, ln_abs) -> Option<Self>
+//! - Mul/Div/Neg, recip(), checked_add/sub (log-sum-exp)
+//! - From<NzFloat>, to_float() / TryFrom<NzLog> for NzFloat
+
+use core::ops::{Div, Mul, Neg};
+
+use crate::math;
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzsign::nzSign;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NzLog {
+    sign: nzSign,
+    ln_abs: f64,
+}
+
+/// Keep a log magnitude inside the finite range (saturate instead of overflowing).
+#[inline]
+fn saturate(ln_abs: f64) -> f64 {
+    ln_abs.clamp(-f64::MAX, f64::MAX)
+}
+
+impl NzLog {
+    /// Create from a sign and ln|x|; rejects non-finite logs (|x| == 0 or inf, or NaN).
+    #[inline]
+    pub fn from_parts(sign: nzSign, ln_abs: f64) -> Option<Self> {
+        if ln_abs.is_finite() {
+            Some(NzLog { sign, ln_abs })
+        } else {
+            None
+        }
+    }
+
+    /// Sign of the represented value.
+    #[inline]
+    pub fn sign(self) -> nzSign {
+        self.sign
+    }
+
+    /// Natural log of the magnitude.
+    #[inline]
+    pub fn ln_abs(self) -> f64 {
+        self.ln_abs
+    }
+
+    /// Construct +1 (ln|x| = 0).
+    #[inline]
+    pub fn one() -> NzLog {
+        NzLog {
+            sign: nzSign::Pos,
+            ln_abs: 0.0,
+        }
+    }
+
+    /// Reciprocal; total because the magnitude is never 0.
+    #[inline]
+    pub fn recip(self) -> NzLog {
+        NzLog {
+            sign: self.sign,
+            ln_abs: -self.ln_abs,
+        }
+    }
+
+    /// Checked addition via log-sum-exp. Returns Err(ZeroResult) if the terms cancel exactly.
+    #[inline]
+    pub fn checked_add(self, rhs: NzLog) -> Result<NzLog, NzfError> {
+        let (hi, lo) = if self.ln_abs >= rhs.ln_abs {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        // d <= 0, so exp(d) lies in [0, 1] and never overflows.
+        let d = lo.ln_abs - hi.ln_abs;
+        let ln_abs = if hi.sign == lo.sign {
+            hi.ln_abs + math::ln_1p(math::exp(d))
+        } else {
+            if d == 0.0 {
+                return Err(NzfError::ZeroResult);
+            }
+            // |hi| - |lo| = |hi| * (1 - e^d) = |hi| * -expm1(d), accurate for small |d|.
+            hi.ln_abs + math::ln(-math::exp_m1(d))
+        };
+        Ok(NzLog {
+            sign: hi.sign,
+            ln_abs: saturate(ln_abs),
+        })
+    }
+
+    /// Checked subtraction via log-sum-exp. Returns Err(ZeroResult) if the operands are equal.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzLog) -> Result<NzLog, NzfError> {
+        self.checked_add(-rhs)
+    }
+
+    /// Convert back to the linear domain. Returns Err(ZeroResult) if exp(ln|x|) underflows to 0.
+    /// Overflow yields ±inf, which NzFloat allows (Err(Infinite) with the `strict-floats` feature).
+    #[inline]
+    pub fn to_float(self) -> Result<NzFloat, NzfError> {
+        let m = math::exp(self.ln_abs);
+        NzFloat::check(if self.sign.is_true() { m } else { -m })
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl Eq for NzLog {}
+
+impl Mul for NzLog {
+    type Output = NzLog;
+    /// Total multiplication: adds the logs and multiplies the signs.
+    #[inline]
+    fn mul(self, rhs: NzLog) -> NzLog {
+        NzLog {
+            sign: if self.sign == rhs.sign {
+                nzSign::Pos
+            } else {
+                nzSign::Neg
+            },
+            ln_abs: saturate(self.ln_abs + rhs.ln_abs),
+        }
+    }
+}
+
+impl Div for NzLog {
+    type Output = NzLog;
+    /// Total division: subtracts the logs and divides the signs.
+    #[inline]
+    fn div(self, rhs: NzLog) -> NzLog {
+        NzLog {
+            sign: if self.sign == rhs.sign {
+                nzSign::Pos
+            } else {
+                nzSign::Neg
+            },
+            ln_abs: saturate(self.ln_abs - rhs.ln_abs),
+        }
+    }
+}
+
+impl Neg for NzLog {
+    type Output = NzLog;
+    #[inline]
+    fn neg(self) -> NzLog {
+        NzLog {
+            sign: self.sign.not(),
+            ln_abs: self.ln_abs,
+        }
+    }
+}
+
+impl From<NzFloat> for NzLog {
+    /// ±inf maps to ln|x| = f64::MAX, following the saturation rule.
+    #[inline]
+    fn from(v: NzFloat) -> Self {
+        let x = v.get();
+        NzLog {
+            sign: nzSign::from_bool(x.is_sign_positive()),
+            ln_abs: saturate(math::ln(x.abs())),
+        }
+    }
+}
+
+impl TryFrom<NzLog> for NzFloat {
+    type Error = NzfError;
+    #[inline]
+    fn try_from(v: NzLog) -> Result<Self, Self::Error> {
+        v.to_float()
+    }
+}
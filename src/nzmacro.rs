@@ -0,0 +1,74 @@
+//! nzmacro: Compile-time checked constructors
+//! API:
+//! - nz!(3) -> NzInt, nz!(-2.5) -> NzFloat, usable in `const` items
+//! - NzInt::new_const / NzFloat::new_const (const fn, panic on zero/NaN)
+//!
+//! Design choices:
+//! - The literal is wrapped in NzLit<T>; separate inherent impls for NzLit<i64> and
+//!   NzLit<f64> let the literal's type pick the target, so one macro covers both
+//! - Evaluated in a `const` block: a zero literal is a compile error, not a runtime panic
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+
+impl NzInt {
+    /// Const constructor. Panics (a compile error in const context) if v == 0.
+    #[inline]
+    pub const fn new_const(v: i64) -> Self {
+        match NzInt::new(v) {
+            Some(nz) => nz,
+            None => panic!("NzInt::new_const: value is zero"),
+        }
+    }
+}
+
+impl NzFloat {
+    /// Const constructor. Panics (a compile error in const context) if v is ±0.0 or NaN.
+    #[inline]
+    pub const fn new_const(v: f64) -> Self {
+        match NzFloat::new(v) {
+            Some(nz) => nz,
+            None => panic!("NzFloat::new_const: value is zero or NaN"),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct NzLit<T>(pub T);
+
+impl NzLit<i64> {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn nz(self) -> NzInt {
+        NzInt::new_const(self.0)
+    }
+}
+
+impl NzLit<f64> {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn nz(self) -> NzFloat {
+        NzFloat::new_const(self.0)
+    }
+}
+
+/// Compile-time checked non-zero literal: `nz!(3)` is an NzInt, `nz!(-2.5)` an NzFloat.
+/// A zero (or NaN) literal fails to compile.
+///
+/// ```
+/// use nz::{nz, NzFloat, NzInt};
+/// const STEP: NzInt = nz!(3);
+/// const RATE: NzFloat = nz!(-2.5);
+/// assert_eq!(STEP.get(), 3);
+/// assert_eq!(RATE.get(), -2.5);
+/// ```
+///
+/// ```compile_fail
+/// const BAD: nz::NzInt = nz::nz!(0);
+/// ```
+#[macro_export]
+macro_rules! nz {
+    ($lit:literal) => {
+        const { $crate::nzmacro::NzLit($lit).nz() }
+    };
+}
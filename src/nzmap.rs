@@ -0,0 +1,58 @@
+//! nzmap: Apply arbitrary primitive functions and re-validate the result
+//! API:
+//! - NzInt::try_map(f: FnOnce(i64) -> i64) -> Result<NzInt, NzError> (Err(ZeroResult) for 0)
+//! - NzFloat::try_map(f: FnOnce(f64) -> f64) -> Result<NzFloat, NzfError> (same checks as checked_*)
+//! - NzResultExt::try_map on Result<NzInt, NzError> / Result<NzFloat, NzfError>, so steps chain:
+//!   x.try_map(|v| v << 3).try_map(|v| v ^ 5)
+//!
+//! Design choices:
+//! - The escape hatch for operations the crate does not wrap (bit tricks, libm calls); the
+//!   closure sees the raw value, and the invariant is checked once on the way back in
+//! - An Err passes through the rest of a chain untouched, so the first failing step is reported
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+
+impl NzInt {
+    /// Apply `f` to the raw value. Returns Err(ZeroResult) if it returns 0.
+    #[inline]
+    pub fn try_map(self, f: impl FnOnce(i64) -> i64) -> Result<NzInt, NzError> {
+        NzInt::new(f(self.get())).ok_or(NzError::ZeroResult)
+    }
+}
+
+impl NzFloat {
+    /// Apply `f` to the raw value. Errors like checked_*: ZeroResult for ±0.0, NotANumber for NaN
+    /// (and Infinite / Underflow under `strict-floats` / `deny-subnormals`).
+    #[inline]
+    pub fn try_map(self, f: impl FnOnce(f64) -> f64) -> Result<NzFloat, NzfError> {
+        NzFloat::check(f(self.get()))
+    }
+}
+
+/// Chaining for results of try_map and the checked_* methods.
+pub trait NzResultExt: Sized {
+    /// The raw primitive (i64 / f64).
+    type Raw;
+
+    /// On Ok, apply `f` to the raw value and re-validate; an Err is returned unchanged.
+    fn try_map(self, f: impl FnOnce(Self::Raw) -> Self::Raw) -> Self;
+}
+
+impl NzResultExt for Result<NzInt, NzError> {
+    type Raw = i64;
+
+    #[inline]
+    fn try_map(self, f: impl FnOnce(i64) -> i64) -> Self {
+        self.and_then(|x| x.try_map(f))
+    }
+}
+
+impl NzResultExt for Result<NzFloat, NzfError> {
+    type Raw = f64;
+
+    #[inline]
+    fn try_map(self, f: impl FnOnce(f64) -> f64) -> Self {
+        self.and_then(|x| x.try_map(f))
+    }
+}
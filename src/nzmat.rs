@@ -0,0 +1,203 @@
+//! nzmat: Invertible 2x2/3x3 matrices over f64 (non-zero determinant)
+//! Invariants:
+//! - Entries are finite; the determinant, as computed in f64, is non-zero
+//! - The inverse is computed once at construction and must satisfy the same invariant
+//!
+//! API:
+//! - NzMat2::new(rows) / NzMat3::new(rows) -> Option<Self>, TryFrom<[[f64; N]; N]>, identity(), to_rows()
+//! - det() -> NzFloat, inverse() -> Self (both total)
+//! - checked_mul -> Result<Self, NzfError>: det(AB) = det(A)·det(B) is non-zero in exact
+//!   arithmetic, but the f64 product can still underflow or overflow
+//! - checked_mul_vec(NzVec) -> Result<NzVec, NzfError>: an invertible matrix maps non-zero to non-zero
+//!
+//! Design choices:
+//! - Storing the inverse alongside the matrix is what makes inverse() total: a non-zero
+//!   determinant alone does not stop adj(A)/det(A) from overflowing when det is tiny.
+//!   inverse() swaps the pair, so A.inverse().inverse() returns A exactly
+//! - Errors follow NzFloat: NaN -> NotANumber, ±inf -> Infinite, singular -> ZeroResult,
+//!   and with `deny-subnormals` a subnormal determinant -> Underflow
+
+use core::fmt;
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzvec::{NzVec2, NzVec3};
+
+#[inline]
+fn det2(m: &[[f64; 2]; 2]) -> f64 {
+    m[0][0] * m[1][1] - m[0][1] * m[1][0]
+}
+
+#[inline]
+fn det3(m: &[[f64; 3]; 3]) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// adj(m) / det for a 2x2 matrix.
+#[inline]
+fn inv2(m: &[[f64; 2]; 2], d: f64) -> [[f64; 2]; 2] {
+    [[m[1][1] / d, -m[0][1] / d], [-m[1][0] / d, m[0][0] / d]]
+}
+
+/// adj(m) / det for a 3x3 matrix; entry (i, j) is the (j, i) cofactor.
+#[inline]
+fn inv3(m: &[[f64; 3]; 3], d: f64) -> [[f64; 3]; 3] {
+    core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / d
+        })
+    })
+}
+
+/// Validate entries and a computed determinant.
+#[inline]
+fn check<const N: usize>(m: &[[f64; N]; N], det: f64) -> Result<(), NzfError> {
+    // Entries first: an infinite entry makes the determinant NaN (inf * 0), and the
+    // overflow is the cause worth reporting. Finite entries and a finite determinant are
+    // part of the invariant, so ±inf fails whatever the features; classify does the rest.
+    let mut entries = m.iter().flatten();
+    let nan = entries.clone().any(|x| x.is_nan());
+    if !nan && (entries.any(|x| x.is_infinite()) || det.is_infinite()) {
+        return Err(NzfError::Infinite);
+    }
+    match NzfError::classify(nan || det.is_nan(), det == 0.0, false, det.is_subnormal()) {
+        None => Ok(()),
+        Some(e) => Err(e),
+    }
+}
+
+#[inline]
+fn mul<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) -> [[f64; N]; N] {
+    core::array::from_fn(|i| core::array::from_fn(|j| (0..N).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+/// Shared API; the struct holds `m` (the matrix) and `inv` (its inverse).
+macro_rules! nz_mat {
+    ($name:ident, $n:literal, $det:ident, $inv:ident, $vec:ident) => {
+        impl $name {
+            /// Matrix from rows; None if it is singular or has a NaN/infinite entry
+            /// (or its inverse does).
+            #[inline]
+            pub fn new(rows: [[f64; $n]; $n]) -> Option<Self> {
+                Self::build(rows).ok()
+            }
+
+            /// The identity matrix.
+            #[inline]
+            pub fn identity() -> Self {
+                let m = core::array::from_fn(|i| {
+                    core::array::from_fn(|j| if i == j { 1.0 } else { 0.0 })
+                });
+                $name { m, inv: m }
+            }
+
+            /// Entries, row by row.
+            #[inline]
+            pub fn to_rows(self) -> [[f64; $n]; $n] {
+                self.m
+            }
+
+            /// Determinant; total.
+            #[inline]
+            pub fn det(self) -> NzFloat {
+                // Checked non-zero and finite at construction.
+                unsafe { NzFloat::new_unchecked($det(&self.m)) }
+            }
+
+            /// Inverse matrix; total.
+            #[inline]
+            pub fn inverse(self) -> Self {
+                $name {
+                    m: self.inv,
+                    inv: self.m,
+                }
+            }
+
+            /// Matrix product self · rhs. Err(ZeroResult) if the determinant underflows,
+            /// Err(Infinite) if an entry, the determinant or the inverse overflows.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Result<Self, NzfError> {
+                Self::build(mul(&self.m, &rhs.m))
+            }
+
+            /// Matrix-vector product self · v. Err(ZeroResult) only on underflow or rounding cancellation.
+            #[inline]
+            pub fn checked_mul_vec(self, v: $vec) -> Result<$vec, NzfError> {
+                let v = v.to_array();
+                let r: [f64; $n] =
+                    core::array::from_fn(|i| (0..$n).map(|k| self.m[i][k] * v[k]).sum());
+                $vec::try_from(r)
+            }
+
+            #[inline]
+            fn build(m: [[f64; $n]; $n]) -> Result<Self, NzfError> {
+                let d = $det(&m);
+                check(&m, d)?;
+                let inv = $inv(&m, d);
+                check(&inv, $det(&inv))?;
+                Ok($name { m, inv })
+            }
+        }
+
+        impl TryFrom<[[f64; $n]; $n]> for $name {
+            type Error = NzfError;
+            #[inline]
+            fn try_from(rows: [[f64; $n]; $n]) -> Result<Self, Self::Error> {
+                Self::build(rows)
+            }
+        }
+
+        impl From<$name> for [[f64; $n]; $n] {
+            #[inline]
+            fn from(x: $name) -> Self {
+                x.m
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.m == other.m
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!(stringify!($name), "{:?}"), self.m)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (i, row) in self.m.iter().enumerate() {
+                    f.write_str(if i > 0 { "; " } else { "" })?;
+                    for (j, x) in row.iter().enumerate() {
+                        f.write_str(if j > 0 { ", " } else { "" })?;
+                        fmt::Display::fmt(x, f)?;
+                    }
+                }
+                f.write_str("]")
+            }
+        }
+    };
+}
+
+/// Invertible 2x2 matrix.
+#[derive(Clone, Copy)]
+pub struct NzMat2 {
+    m: [[f64; 2]; 2],
+    inv: [[f64; 2]; 2],
+}
+
+/// Invertible 3x3 matrix.
+#[derive(Clone, Copy)]
+pub struct NzMat3 {
+    m: [[f64; 3]; 3],
+    inv: [[f64; 3]; 3],
+}
+
+nz_mat!(NzMat2, 2, det2, inv2, NzVec2);
+nz_mat!(NzMat3, 3, det3, inv3, NzVec3);
@@ -0,0 +1,139 @@
+//! nznumtraits: num-traits interop (feature `num-traits`)
+//! Provided for NzInt and NzFloat:
+//! - One
+//! - CheckedAdd/CheckedSub/CheckedMul/CheckedDiv: None when the result would be zero, NaN, or
+//!   (NzInt) overflow; NzFloat also returns None for ±inf with `strict-floats`
+//! - ToPrimitive (exact or None, as for i64/f64), FromPrimitive (None for 0 and NaN)
+//!
+//! Design choices:
+//! - No Zero, Num, or Signed: Num requires Zero, which these types cannot provide.
+//!   Inherent abs()/signum() cover what Signed would
+//! - The Checked* traits follow num-traits and return None on integer overflow, unlike the
+//!   inherent NzInt::checked_* methods, which wrap
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, One, ToPrimitive};
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+
+impl One for NzInt {
+    #[inline]
+    fn one() -> Self {
+        NzInt::one()
+    }
+}
+
+impl One for NzFloat {
+    #[inline]
+    fn one() -> Self {
+        NzFloat::one()
+    }
+}
+
+macro_rules! int_checked {
+    ($tr:ident, $m:ident) => {
+        impl $tr for NzInt {
+            #[inline]
+            fn $m(&self, rhs: &Self) -> Option<Self> {
+                self.get().$m(rhs.get()).and_then(NzInt::new)
+            }
+        }
+    };
+}
+
+int_checked!(CheckedAdd, checked_add);
+int_checked!(CheckedSub, checked_sub);
+int_checked!(CheckedMul, checked_mul);
+int_checked!(CheckedDiv, checked_div);
+
+macro_rules! float_checked {
+    ($tr:ident, $m:ident) => {
+        impl $tr for NzFloat {
+            #[inline]
+            fn $m(&self, rhs: &Self) -> Option<Self> {
+                NzFloat::$m(*self, *rhs).ok()
+            }
+        }
+    };
+}
+
+float_checked!(CheckedAdd, checked_add);
+float_checked!(CheckedSub, checked_sub);
+float_checked!(CheckedMul, checked_mul);
+float_checked!(CheckedDiv, checked_div);
+
+impl ToPrimitive for NzInt {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.get())
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        Some(self.get().into())
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.get().to_f64()
+    }
+}
+
+impl ToPrimitive for NzFloat {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.get().to_i128()
+    }
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.get().to_u128()
+    }
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        self.get().to_f32()
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.get())
+    }
+}
+
+impl FromPrimitive for NzInt {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        NzInt::new(n)
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        i64::try_from(n).ok().and_then(NzInt::new)
+    }
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        i64::from_f64(n).and_then(NzInt::new)
+    }
+}
+
+impl FromPrimitive for NzFloat {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        NzFloat::new(n as f64)
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        NzFloat::new(n as f64)
+    }
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        NzFloat::new(n)
+    }
+}
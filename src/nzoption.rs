@@ -0,0 +1,55 @@
+//! nzoption: Option<NzInt>/Option<NzFloat> as "zero means absent"
+//! Model:
+//! - None stands for 0, so Option<NzInt> covers every i64 and Option<NzFloat> every non-NaN f64
+//! - Arithmetic is total in this model: a zero result is simply None
+//!
+//! API (NzOptionExt):
+//! - map_checked_add(rhs), unwrap_or_one(), sum_present(iter)
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+
+pub trait NzOptionExt: Sized {
+    type Value;
+
+    /// Add rhs, treating None as 0. Returns None if the sum is zero.
+    fn map_checked_add(self, rhs: Self::Value) -> Self;
+
+    /// The value, or 1 if absent.
+    fn unwrap_or_one(self) -> Self::Value;
+
+    /// Sum of all present values (None counts as 0). Returns None if the total is zero.
+    fn sum_present<I: IntoIterator<Item = Self>>(iter: I) -> Self;
+}
+
+macro_rules! nz_option_ext {
+    ($ty:ident) => {
+        impl NzOptionExt for Option<$ty> {
+            type Value = $ty;
+
+            #[inline]
+            fn map_checked_add(self, rhs: $ty) -> Self {
+                match self {
+                    Some(v) => v.checked_add(rhs).ok(),
+                    None => Some(rhs),
+                }
+            }
+
+            #[inline]
+            fn unwrap_or_one(self) -> $ty {
+                self.unwrap_or($ty::one())
+            }
+
+            fn sum_present<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+                iter.into_iter()
+                    .flatten()
+                    .fold(None, |acc, v| acc.map_checked_add(v))
+            }
+        }
+    };
+}
+
+// NzInt sums wrap modulo 2^64 like NzInt::checked_add.
+nz_option_ext!(NzInt);
+// inf + -inf has no value in the model either and also yields None.
+nz_option_ext!(NzFloat);
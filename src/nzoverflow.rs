@@ -0,0 +1,402 @@
+//! nzoverflow: Explicit overflow policy for NzInt / NzFloat arithmetic
+//! API:
+//! - OverflowPolicy::{Strict, Wrapping, Saturating}
+//! - NzInt: add_with/sub_with/mul_with/div_with(rhs, policy), neg_with/abs_with(policy),
+//!   pow_with(exp, policy), shl_with(n, policy) -> Result
+//! - NzFloat: add_with/sub_with/mul_with/div_with(rhs, policy), powf_with(exp, policy) -> Result
+//! - Strict<T>: typestate wrapper whose checked_* use OverflowPolicy::Strict, and whose
+//!   operators (+ - * / and unary -, plus the *Assign forms) panic on overflow as well as on zero
+//!
+//! Design choices:
+//! - The policy only decides what happens on overflow; a zero result is Err(ZeroResult) under
+//!   every policy, since no policy can make zero representable
+//! - Wrapping is the plain checked_* method (the Wrapping arm calls it), so those keep their
+//!   meaning and callers opt into Strict or Saturating where a wrapped value would be wrong.
+//!   Only NzInt add/sub/mul actually wrap: checked_div/neg/abs report i64::MIN / -1 and
+//!   -i64::MIN as Err(DivOverflow), and checked_pow/shl report Err(Overflow), so Wrapping does too
+//! - NzInt: Strict reports Err(NzError::Overflow), Saturating clamps to i64::MIN/MAX
+//!   (i64::MIN / -1, -i64::MIN and |i64::MIN| all saturate to i64::MAX)
+//! - NzFloat: overflow means finite operands with an infinite result. Strict reports
+//!   Err(NzfError::Infinite), Saturating clamps to ±f64::MAX, Wrapping keeps the ±inf
+//!   (subject to `strict-floats` as usual). Operations on infinities are not overflow;
+//!   NzFloat negation and abs are total, so they need no policy
+//! - Only the operations listed above take a policy. The rest (rem, the euclid and pow10/pow2
+//!   variants, mod_pow, lcm, ...) exist only as checked_*, and Strict<T> does not wrap them
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::nzfloat::{self, NzFloat, NzfError};
+use crate::nzint::{self, NzError, NzInt};
+
+/// What an arithmetic operation does when the exact result does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Fail with an overflow error.
+    Strict,
+    /// Wrap (integers) or overflow to ±inf (floats), as checked_* does.
+    #[default]
+    Wrapping,
+    /// Clamp to the largest finite value of the right sign.
+    Saturating,
+}
+
+/* ----- NzInt ----- */
+
+impl NzInt {
+    /// Addition with an explicit overflow policy. Err(ZeroResult) if the result is zero.
+    #[inline]
+    pub fn add_with(self, rhs: NzInt, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let (a, b) = (self.get(), rhs.get());
+        int_result(
+            policy,
+            a.checked_add(b),
+            a.saturating_add(b),
+            self.checked_add(rhs),
+        )
+    }
+
+    /// Subtraction with an explicit overflow policy. Err(ZeroResult) if the result is zero.
+    #[inline]
+    pub fn sub_with(self, rhs: NzInt, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let (a, b) = (self.get(), rhs.get());
+        int_result(
+            policy,
+            a.checked_sub(b),
+            a.saturating_sub(b),
+            self.checked_sub(rhs),
+        )
+    }
+
+    /// Multiplication with an explicit overflow policy. Err(ZeroResult) if the product wraps to zero.
+    #[inline]
+    pub fn mul_with(self, rhs: NzInt, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let (a, b) = (self.get(), rhs.get());
+        int_result(
+            policy,
+            a.checked_mul(b),
+            a.saturating_mul(b),
+            self.checked_mul(rhs),
+        )
+    }
+
+    /// Truncating division with an explicit overflow policy; only i64::MIN / -1 overflows,
+    /// and Wrapping reports it as Err(DivOverflow) like checked_div.
+    /// Err(ZeroResult) if the quotient truncates to zero.
+    #[inline]
+    pub fn div_with(self, rhs: NzInt, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let (a, b) = (self.get(), rhs.get());
+        int_result(
+            policy,
+            a.checked_div(b),
+            a.saturating_div(b),
+            self.checked_div(rhs),
+        )
+    }
+
+    /// Negation with an explicit overflow policy; only i64::MIN overflows.
+    #[inline]
+    pub fn neg_with(self, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let a = self.get();
+        int_result(
+            policy,
+            a.checked_neg(),
+            a.saturating_neg(),
+            self.checked_neg(),
+        )
+    }
+
+    /// Absolute value with an explicit overflow policy; only i64::MIN overflows.
+    #[inline]
+    pub fn abs_with(self, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let a = self.get();
+        int_result(
+            policy,
+            a.checked_abs(),
+            a.saturating_abs(),
+            self.checked_abs(),
+        )
+    }
+
+    /// Exponentiation with an explicit overflow policy (a power of non-zero is never zero).
+    #[inline]
+    pub fn pow_with(self, exp: u32, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let a = self.get();
+        int_result(
+            policy,
+            a.checked_pow(exp),
+            a.saturating_pow(exp),
+            self.checked_pow(exp),
+        )
+    }
+
+    /// Left shift (multiplication by 2^n) with an explicit overflow policy; overflow is
+    /// n >= 64 or a significant bit shifted out, and saturates toward the sign of self.
+    #[inline]
+    pub fn shl_with(self, n: u32, policy: OverflowPolicy) -> Result<NzInt, NzError> {
+        let a = self.get();
+        let exact = a.checked_shl(n).filter(|r| r >> n == a);
+        int_result(
+            policy,
+            exact,
+            if a > 0 { i64::MAX } else { i64::MIN },
+            self.checked_shl(n),
+        )
+    }
+}
+
+/// Pick the policy's result: `exact` is None on overflow, and `checked` is the plain
+/// checked_* result that Wrapping stands for.
+#[inline]
+fn int_result(
+    policy: OverflowPolicy,
+    exact: Option<i64>,
+    saturated: i64,
+    checked: Result<NzInt, NzError>,
+) -> Result<NzInt, NzError> {
+    let r = match policy {
+        OverflowPolicy::Strict => exact.ok_or(NzError::Overflow)?,
+        OverflowPolicy::Wrapping => return checked,
+        OverflowPolicy::Saturating => saturated,
+    };
+    NzInt::new(r).ok_or(NzError::ZeroResult)
+}
+
+/* ----- NzFloat ----- */
+
+impl NzFloat {
+    /// Addition with an explicit overflow policy.
+    #[inline]
+    pub fn add_with(self, rhs: NzFloat, policy: OverflowPolicy) -> Result<NzFloat, NzfError> {
+        float_result(self, rhs, self.get() + rhs.get(), policy)
+    }
+
+    /// Subtraction with an explicit overflow policy.
+    #[inline]
+    pub fn sub_with(self, rhs: NzFloat, policy: OverflowPolicy) -> Result<NzFloat, NzfError> {
+        float_result(self, rhs, self.get() - rhs.get(), policy)
+    }
+
+    /// Multiplication with an explicit overflow policy.
+    #[inline]
+    pub fn mul_with(self, rhs: NzFloat, policy: OverflowPolicy) -> Result<NzFloat, NzfError> {
+        float_result(self, rhs, self.get() * rhs.get(), policy)
+    }
+
+    /// Division with an explicit overflow policy (e.g., 1e300 / 1e-300 overflows).
+    #[inline]
+    pub fn div_with(self, rhs: NzFloat, policy: OverflowPolicy) -> Result<NzFloat, NzfError> {
+        float_result(self, rhs, self.get() / rhs.get(), policy)
+    }
+
+    /// self^exp with an explicit overflow policy (e.g., 10^400 overflows).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn powf_with(self, exp: NzFloat, policy: OverflowPolicy) -> Result<NzFloat, NzfError> {
+        float_result(self, exp, math::powf(self.get(), exp.get()), policy)
+    }
+}
+
+/// Apply the policy to a raw result, then validate it like checked_*.
+#[inline]
+fn float_result(
+    a: NzFloat,
+    b: NzFloat,
+    r: f64,
+    policy: OverflowPolicy,
+) -> Result<NzFloat, NzfError> {
+    let overflowed = r.is_infinite() && a.get().is_finite() && b.get().is_finite();
+    let r = match policy {
+        OverflowPolicy::Strict if overflowed => return Err(NzfError::Infinite),
+        OverflowPolicy::Saturating if overflowed => f64::MAX.copysign(r),
+        _ => r,
+    };
+    NzFloat::check(r)
+}
+
+/* ----- Strict typestate ----- */
+
+/// A value whose arithmetic never wraps: every checked_* uses OverflowPolicy::Strict,
+/// and the operators panic on overflow.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Strict<T>(T);
+
+impl<T> Strict<T> {
+    /// Wrap a value.
+    #[inline]
+    pub const fn new(x: T) -> Self {
+        Strict(x)
+    }
+
+    /// The wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! strict_ops {
+    ($t:ty, $err:ty) => {
+        impl Strict<$t> {
+            /// Addition; Err on overflow or a zero result.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Result<Self, $err> {
+                self.0.add_with(rhs.0, OverflowPolicy::Strict).map(Strict)
+            }
+
+            /// Subtraction; Err on overflow or a zero result.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Result<Self, $err> {
+                self.0.sub_with(rhs.0, OverflowPolicy::Strict).map(Strict)
+            }
+
+            /// Multiplication; Err on overflow or a zero result.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Result<Self, $err> {
+                self.0.mul_with(rhs.0, OverflowPolicy::Strict).map(Strict)
+            }
+
+            /// Division; Err on overflow or a zero result.
+            #[inline]
+            pub fn checked_div(self, rhs: Self) -> Result<Self, $err> {
+                self.0.div_with(rhs.0, OverflowPolicy::Strict).map(Strict)
+            }
+
+            /// The wrapped value.
+            #[inline]
+            pub const fn get(self) -> $t {
+                self.0
+            }
+        }
+
+        impl From<$t> for Strict<$t> {
+            #[inline]
+            fn from(x: $t) -> Self {
+                Strict(x)
+            }
+        }
+
+        impl From<Strict<$t>> for $t {
+            #[inline]
+            fn from(x: Strict<$t>) -> Self {
+                x.0
+            }
+        }
+    };
+}
+
+strict_ops!(NzInt, NzError);
+strict_ops!(NzFloat, NzfError);
+
+impl Strict<NzInt> {
+    /// Negation; Err(Overflow) for i64::MIN.
+    #[inline]
+    pub fn checked_neg(self) -> Result<Self, NzError> {
+        self.0.neg_with(OverflowPolicy::Strict).map(Strict)
+    }
+
+    /// Absolute value; Err(Overflow) for i64::MIN.
+    #[inline]
+    pub fn checked_abs(self) -> Result<Self, NzError> {
+        self.0.abs_with(OverflowPolicy::Strict).map(Strict)
+    }
+
+    /// Exponentiation; Err(Overflow) if the result does not fit.
+    #[inline]
+    pub fn checked_pow(self, exp: u32) -> Result<Self, NzError> {
+        self.0.pow_with(exp, OverflowPolicy::Strict).map(Strict)
+    }
+
+    /// Left shift; Err(Overflow) if n >= 64 or a significant bit is shifted out.
+    #[inline]
+    pub fn checked_shl(self, n: u32) -> Result<Self, NzError> {
+        self.0.shl_with(n, OverflowPolicy::Strict).map(Strict)
+    }
+}
+
+impl Strict<NzFloat> {
+    /// self^exp; Err(Infinite) if finite operands overflow.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn checked_powf(self, exp: Self) -> Result<Self, NzfError> {
+        self.0.powf_with(exp.0, OverflowPolicy::Strict).map(Strict)
+    }
+}
+
+/// Operators on Strict<T>: like T's own, but panicking on overflow as well as on a zero result.
+macro_rules! strict_operators {
+    (
+        $t:ty, $unwrap:path,
+        $($trait:ident $method:ident $assign_trait:ident $assign_method:ident $checked:ident $verb:literal),*
+    ) => {
+        $(
+            impl $trait for Strict<$t> {
+                type Output = Self;
+                #[inline]
+                #[track_caller]
+                fn $method(self, rhs: Self) -> Self {
+                    Strict($unwrap(self.$checked(rhs).map(Strict::<$t>::get), $verb))
+                }
+            }
+
+            impl $assign_trait for Strict<$t> {
+                #[inline]
+                #[track_caller]
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+strict_operators!(
+    NzInt, nzint::unwrap_op,
+    Add add AddAssign add_assign checked_add "add",
+    Sub sub SubAssign sub_assign checked_sub "subtract",
+    Mul mul MulAssign mul_assign checked_mul "multiply",
+    Div div DivAssign div_assign checked_div "divide"
+);
+strict_operators!(
+    NzFloat, nzfloat::unwrap_op,
+    Add add AddAssign add_assign checked_add "add",
+    Sub sub SubAssign sub_assign checked_sub "subtract",
+    Mul mul MulAssign mul_assign checked_mul "multiply",
+    Div div DivAssign div_assign checked_div "divide"
+);
+
+impl Neg for Strict<NzInt> {
+    type Output = Self;
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self {
+        Strict(nzint::unwrap_op(
+            self.0.neg_with(OverflowPolicy::Strict),
+            "negate",
+        ))
+    }
+}
+
+impl Neg for Strict<NzFloat> {
+    type Output = Self;
+    /// Total, as for NzFloat.
+    #[inline]
+    fn neg(self) -> Self {
+        Strict(-self.0)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Strict<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Strict").field(&self.0).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Strict<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
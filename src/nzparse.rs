@@ -0,0 +1,77 @@
+//! nzparse: Parsing text straight into the non-zero domain
+//! API:
+//! - FromStr for NzInt, NzFloat, nzSign
+//! - NzInt::from_str_radix(s, radix) for bases 2..=36
+//! - NzParseError separates malformed text from text that parses to 0 or NaN
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzsign::nzSign;
+
+/// Error type for parsing nz values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NzParseError {
+    /// The text is not a valid number/sign for the target type (including i64 overflow).
+    Invalid,
+    /// The text is well-formed but denotes 0 (or ±0.0).
+    Zero,
+    /// The text is well-formed but denotes NaN.
+    NotANumber,
+}
+
+impl fmt::Display for NzParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NzParseError::Invalid => "invalid syntax",
+            NzParseError::Zero => "value is zero",
+            NzParseError::NotANumber => "value is NaN",
+        })
+    }
+}
+
+impl core::error::Error for NzParseError {}
+
+impl NzInt {
+    /// Parse an integer in the given base (like i64::from_str_radix).
+    /// # Panics
+    /// Panics if radix is not in 2..=36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<NzInt, NzParseError> {
+        let v = i64::from_str_radix(s, radix).map_err(|_| NzParseError::Invalid)?;
+        NzInt::new(v).ok_or(NzParseError::Zero)
+    }
+}
+
+impl FromStr for NzInt {
+    type Err = NzParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NzInt::from_str_radix(s, 10)
+    }
+}
+
+impl FromStr for NzFloat {
+    type Err = NzParseError;
+    /// Accepts everything f64 parses ("inf" included); "0.0"/"-0" -> Zero, "NaN" -> NotANumber.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v: f64 = s.parse().map_err(|_| NzParseError::Invalid)?;
+        if v.is_nan() {
+            return Err(NzParseError::NotANumber);
+        }
+        NzFloat::new(v).ok_or(NzParseError::Zero)
+    }
+}
+
+impl FromStr for nzSign {
+    type Err = NzParseError;
+    /// Accepts "1"/"+1"/"+"/"true"/"Pos" and "-1"/"-"/"false"/"Neg"; "0" -> Zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "+1" | "+" | "true" | "Pos" => Ok(nzSign::Pos),
+            "-1" | "-" | "false" | "Neg" => Ok(nzSign::Neg),
+            "0" | "+0" | "-0" => Err(NzParseError::Zero),
+            _ => Err(NzParseError::Invalid),
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! nzpositive: Strictly positive and strictly negative refinements of NzInt/NzFloat
+//! Invariants:
+//! - PzInt: 0 < value <= i64::MAX; NgInt: -i64::MAX <= value < 0 (i64::MIN excluded)
+//! - PzFloat: 0 < value <= +inf; NgFloat: -inf <= value < 0 (never NaN)
+//!
+//! API:
+//! - new(v) -> Option<Self>, get(), From into NzInt/NzFloat, TryFrom back (Err(OutOfRange) on a sign mismatch)
+//! - abs() and unary minus (Neg): total, mapping between the positive and negative types
+//! - checked_add/checked_mul (ints: overflow only; floats: underflow to zero, plus the
+//!   `strict-floats` / `deny-subnormals` rules), saturating_add/saturating_mul (never fail)
+//! - NzInt::to_sign_magnitude() -> (nzSign, PzInt), NzFloat::to_sign_magnitude() -> (nzSign, PzFloat)
+//! - PzInt/PzFloat::with_sign(nzSign) to recombine
+//! - PzInt::ilog2/ilog10/prev_power_of_two (total), is_power_of_two, checked_next_power_of_two
+//!
+//! Design choices:
+//! - Int ranges are symmetric so abs/neg are bijections; i64::MIN has no sign-magnitude form
+//! - Sums and products of positives cannot reach zero or change sign; only magnitude can fail
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::NonZeroI64;
+use core::ops::Neg;
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nzint::{NzError, NzInt};
+use crate::nzsign::nzSign;
+
+/// Strictly positive i64.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PzInt(NonZeroI64);
+
+/// Strictly negative i64 in [-i64::MAX, -1].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NgInt(NonZeroI64);
+
+/// Strictly positive f64 (+inf allowed).
+/// repr(transparent): batch::split_signs builds PzFloat buffers as raw f64.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct PzFloat(f64);
+
+/// Strictly negative f64 (-inf allowed).
+#[derive(Clone, Copy)]
+pub struct NgFloat(f64);
+
+impl PzInt {
+    /// Create from i64. Returns None unless v > 0.
+    #[inline]
+    pub const fn new(v: i64) -> Option<Self> {
+        if v > 0 {
+            Some(PzInt(unsafe { NonZeroI64::new_unchecked(v) }))
+        } else {
+            None
+        }
+    }
+
+    /// Get the inner i64.
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.0.get()
+    }
+
+    /// Absolute value; the identity.
+    #[inline]
+    pub fn abs(self) -> PzInt {
+        self
+    }
+
+    /// Checked addition. Err(Overflow) is the only failure.
+    #[inline]
+    pub fn checked_add(self, rhs: PzInt) -> Result<PzInt, NzError> {
+        self.get()
+            .checked_add(rhs.get())
+            .and_then(PzInt::new)
+            .ok_or(NzError::Overflow)
+    }
+
+    /// Checked multiplication. Err(Overflow) is the only failure.
+    #[inline]
+    pub fn checked_mul(self, rhs: PzInt) -> Result<PzInt, NzError> {
+        self.get()
+            .checked_mul(rhs.get())
+            .and_then(PzInt::new)
+            .ok_or(NzError::Overflow)
+    }
+
+    /// Saturating addition; never fails (clamps to i64::MAX).
+    #[inline]
+    pub fn saturating_add(self, rhs: PzInt) -> PzInt {
+        PzInt(unsafe { NonZeroI64::new_unchecked(self.get().saturating_add(rhs.get())) })
+    }
+
+    /// Saturating multiplication; never fails (clamps to i64::MAX).
+    #[inline]
+    pub fn saturating_mul(self, rhs: PzInt) -> PzInt {
+        PzInt(unsafe { NonZeroI64::new_unchecked(self.get().saturating_mul(rhs.get())) })
+    }
+
+    /// floor(log2(self)); total, since self > 0.
+    #[inline]
+    pub const fn ilog2(self) -> u32 {
+        self.get().ilog2()
+    }
+
+    /// floor(log10(self)); total, since self > 0.
+    #[inline]
+    pub const fn ilog10(self) -> u32 {
+        self.get().ilog10()
+    }
+
+    /// True if self is 2^k.
+    #[inline]
+    pub const fn is_power_of_two(self) -> bool {
+        (self.get() as u64).is_power_of_two()
+    }
+
+    /// Smallest power of two >= self. Err(Overflow) above 2^62, whose successor 2^63 is not an i64.
+    #[inline]
+    pub fn checked_next_power_of_two(self) -> Result<PzInt, NzError> {
+        let p = (self.get() as u64).next_power_of_two();
+        PzInt::new(p as i64).ok_or(NzError::Overflow)
+    }
+
+    /// Largest power of two <= self; total.
+    #[inline]
+    pub const fn prev_power_of_two(self) -> PzInt {
+        PzInt(unsafe { NonZeroI64::new_unchecked(1 << self.ilog2()) })
+    }
+
+    /// Recombine with a sign; total.
+    #[inline]
+    pub fn with_sign(self, sign: nzSign) -> NzInt {
+        match sign {
+            nzSign::Pos => self.into(),
+            nzSign::Neg => (-self).into(),
+        }
+    }
+}
+
+impl NgInt {
+    /// Create from i64. Returns None unless -i64::MAX <= v < 0.
+    #[inline]
+    pub const fn new(v: i64) -> Option<Self> {
+        if v < 0 && v != i64::MIN {
+            Some(NgInt(unsafe { NonZeroI64::new_unchecked(v) }))
+        } else {
+            None
+        }
+    }
+
+    /// Get the inner i64.
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.0.get()
+    }
+
+    /// Absolute value; total thanks to the symmetric range.
+    #[inline]
+    pub fn abs(self) -> PzInt {
+        PzInt(unsafe { NonZeroI64::new_unchecked(-self.get()) })
+    }
+
+    /// Checked addition. Err(Overflow) is the only failure.
+    #[inline]
+    pub fn checked_add(self, rhs: NgInt) -> Result<NgInt, NzError> {
+        self.get()
+            .checked_add(rhs.get())
+            .and_then(NgInt::new)
+            .ok_or(NzError::Overflow)
+    }
+
+    /// Saturating addition; never fails (clamps to -i64::MAX).
+    #[inline]
+    pub fn saturating_add(self, rhs: NgInt) -> NgInt {
+        let r = self.get().saturating_add(rhs.get()).max(-i64::MAX);
+        NgInt(unsafe { NonZeroI64::new_unchecked(r) })
+    }
+
+    /// Product of two negatives. Err(Overflow) is the only failure.
+    #[inline]
+    pub fn checked_mul(self, rhs: NgInt) -> Result<PzInt, NzError> {
+        self.abs().checked_mul(rhs.abs())
+    }
+}
+
+impl PzFloat {
+    /// Create from f64. Returns None unless v > 0.0 (NaN rejected).
+    #[inline]
+    pub fn new(v: f64) -> Option<Self> {
+        if v > 0.0 { Some(PzFloat(v)) } else { None }
+    }
+
+    /// Get the inner f64.
+    #[inline]
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Absolute value; the identity.
+    #[inline]
+    pub fn abs(self) -> PzFloat {
+        self
+    }
+
+    /// Checked addition. Fails only under the feature rules: Err(Infinite) for an infinite sum
+    /// with `strict-floats`, Err(Underflow) for a subnormal sum (of two subnormals) with
+    /// `deny-subnormals`.
+    #[inline]
+    pub fn checked_add(self, rhs: PzFloat) -> Result<PzFloat, NzfError> {
+        check_pz(self.0 + rhs.0)
+    }
+
+    /// Checked multiplication. Err(ZeroResult) on underflow to zero (1e-200 * 1e-200); with
+    /// `strict-floats` also Err(Infinite) for an infinite product, and with `deny-subnormals`
+    /// Err(Underflow) for a subnormal one (1e-160 * 1e-160).
+    #[inline]
+    pub fn checked_mul(self, rhs: PzFloat) -> Result<PzFloat, NzfError> {
+        check_pz(self.0 * rhs.0)
+    }
+
+    /// Saturating addition; never fails (+inf is kept).
+    #[inline]
+    pub fn saturating_add(self, rhs: PzFloat) -> PzFloat {
+        PzFloat(self.0 + rhs.0)
+    }
+
+    /// Saturating multiplication; never fails. Underflow snaps to the smallest subnormal (5e-324).
+    #[inline]
+    pub fn saturating_mul(self, rhs: PzFloat) -> PzFloat {
+        let r = self.0 * rhs.0;
+        PzFloat(if r == 0.0 { f64::from_bits(1) } else { r })
+    }
+
+    /// Recombine with a sign; total.
+    #[inline]
+    pub fn with_sign(self, sign: nzSign) -> NzFloat {
+        match sign {
+            nzSign::Pos => self.into(),
+            nzSign::Neg => (-self).into(),
+        }
+    }
+}
+
+impl NgFloat {
+    /// Create from f64. Returns None unless v < 0.0 (NaN rejected).
+    #[inline]
+    pub fn new(v: f64) -> Option<Self> {
+        if v < 0.0 { Some(NgFloat(v)) } else { None }
+    }
+
+    /// Get the inner f64.
+    #[inline]
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Absolute value; total.
+    #[inline]
+    pub fn abs(self) -> PzFloat {
+        PzFloat(-self.0)
+    }
+
+    /// Product of two negatives; fails like PzFloat::checked_mul.
+    #[inline]
+    pub fn checked_mul(self, rhs: NgFloat) -> Result<PzFloat, NzfError> {
+        check_pz(self.0 * rhs.0)
+    }
+}
+
+/// Validate a positive-by-construction float result (only underflow and inf can occur).
+#[inline]
+fn check_pz(r: f64) -> Result<PzFloat, NzfError> {
+    NzFloat::check(r).map(|x| PzFloat(x.get()))
+}
+
+/* ----- Sign-magnitude decomposition ----- */
+
+impl NzInt {
+    /// Split into sign and magnitude. Err(DivOverflow) for i64::MIN, whose magnitude is not an i64.
+    #[inline]
+    pub fn to_sign_magnitude(self) -> Result<(nzSign, PzInt), NzError> {
+        let v = self.get();
+        if v > 0 {
+            Ok((nzSign::Pos, PzInt(unsafe { NonZeroI64::new_unchecked(v) })))
+        } else {
+            let m = v.checked_neg().ok_or(NzError::DivOverflow)?;
+            Ok((nzSign::Neg, PzInt(unsafe { NonZeroI64::new_unchecked(m) })))
+        }
+    }
+}
+
+impl NzFloat {
+    /// Split into sign and magnitude; total.
+    #[inline]
+    pub fn to_sign_magnitude(self) -> (nzSign, PzFloat) {
+        (self.sign(), PzFloat(self.get().abs()))
+    }
+}
+
+/* ----- Trait impls ----- */
+
+macro_rules! refinement_impls {
+    ($name:ident => $base:ident) => {
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl From<$name> for $base {
+            #[inline]
+            fn from(v: $name) -> $base {
+                unsafe { $base::new_unchecked(v.get()) }
+            }
+        }
+
+        impl TryFrom<$base> for $name {
+            type Error = NzError;
+            #[inline]
+            fn try_from(v: $base) -> Result<Self, Self::Error> {
+                $name::new(v.get()).ok_or(NzError::OutOfRange)
+            }
+        }
+    };
+}
+
+/// Float refinements exclude NaN and ±0.0, so bit-level Eq/Ord/Hash are sound.
+macro_rules! float_order_impls {
+    ($name:ident) => {
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state)
+            }
+        }
+    };
+}
+
+impl Neg for PzInt {
+    type Output = NgInt;
+    /// Total thanks to the symmetric range.
+    #[inline]
+    fn neg(self) -> NgInt {
+        NgInt(unsafe { NonZeroI64::new_unchecked(-self.get()) })
+    }
+}
+
+impl Neg for NgInt {
+    type Output = PzInt;
+    #[inline]
+    fn neg(self) -> PzInt {
+        self.abs()
+    }
+}
+
+impl Neg for PzFloat {
+    type Output = NgFloat;
+    #[inline]
+    fn neg(self) -> NgFloat {
+        NgFloat(-self.0)
+    }
+}
+
+impl Neg for NgFloat {
+    type Output = PzFloat;
+    #[inline]
+    fn neg(self) -> PzFloat {
+        self.abs()
+    }
+}
+
+refinement_impls!(PzInt => NzInt);
+refinement_impls!(NgInt => NzInt);
+refinement_impls!(PzFloat => NzFloat);
+refinement_impls!(NgFloat => NzFloat);
+float_order_impls!(PzFloat);
+float_order_impls!(NgFloat);
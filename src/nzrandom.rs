@@ -0,0 +1,249 @@
+//! nzrandom: Random sampling in the non-zero domain (`rand` feature)
+//! API:
+//! - choose_weighted(items, weights, rng) -> Option<&T> (None only for empty input)
+//! - NzWeightedIndex::new(weights) + sample(rng): alias method, O(1) per draw (needs `alloc`)
+//! - Distribution<NzInt/NzFloat> for StandardUniform: every non-zero i64 / (0, 1]
+//! - NzUniform::<NzInt/NzFloat>::new(low, high): uniform over [low, high) minus zero
+//!
+//! Design choices:
+//! - Samplers never reject: integer ranges are indexed with zero skipped, and float ranges
+//!   pick the negative or positive side first (by length), then sample inside that side
+//! - Weights are PzFloat rather than NzFloat: a negative weight is as meaningless as a zero one,
+//!   and with every weight > 0 the zero-total failure mode cannot occur
+//! - Weights are rescaled by their maximum first, so huge weights cannot overflow the total;
+//!   if any weight is +inf, the infinite weights share all of the probability equally
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use rand::Rng;
+use rand::distr::{Distribution, Open01, StandardUniform};
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzpositive::PzFloat;
+use crate::nzrange::NzRange;
+use crate::nzstats::{max_weight, scaled};
+
+/// Pick one of `items` with probability proportional to its weight.
+/// Returns None only if `items` is empty.
+/// # Panics
+/// Panics if `items` and `weights` have different lengths.
+pub fn choose_weighted<'a, T, R: Rng + ?Sized>(
+    items: &'a [T],
+    weights: &[PzFloat],
+    rng: &mut R,
+) -> Option<&'a T> {
+    assert_eq!(
+        items.len(),
+        weights.len(),
+        "choose_weighted: items and weights differ in length"
+    );
+    let scale = max_weight(weights)?;
+    let total: f64 = weights.iter().map(|&w| scaled(w, scale)).sum();
+    let mut r = rng.random::<f64>() * total;
+    for (item, &w) in items.iter().zip(weights) {
+        let w = scaled(w, scale);
+        if r < w {
+            return Some(item);
+        }
+        r -= w;
+    }
+    // Rounding can leave r just above the last cumulative bound; fall back to the
+    // last item that carries any weight.
+    items
+        .iter()
+        .zip(weights)
+        .rev()
+        .find(|&(_, &w)| scaled(w, scale) > 0.0)
+        .map(|(item, _)| item)
+}
+
+/// Precomputed alias table (Vose's method) for repeated sampling of indices.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct NzWeightedIndex {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl NzWeightedIndex {
+    /// Build the table in O(n). Returns None if `weights` is empty.
+    pub fn new(weights: &[PzFloat]) -> Option<Self> {
+        let scale = max_weight(weights)?;
+        let n = weights.len();
+        let total: f64 = weights.iter().map(|&w| scaled(w, scale)).sum();
+        // Mean bucket height is 1.0 after this rescaling.
+        let mut prob: Vec<f64> = weights
+            .iter()
+            .map(|&w| scaled(w, scale) * n as f64 / total)
+            .collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| prob[i] < 1.0);
+        while let (Some(s), Some(&l)) = (small.pop(), large.last()) {
+            alias[s] = l;
+            prob[l] -= 1.0 - prob[s];
+            if prob[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // Leftovers are 1.0 up to rounding.
+        for i in small.into_iter().chain(large) {
+            prob[i] = 1.0;
+        }
+        Some(NzWeightedIndex { prob, alias })
+    }
+
+    /// Number of weights (at least 1).
+    #[inline]
+    pub fn len(&self) -> core::num::NonZeroUsize {
+        // new() rejects empty weights.
+        unsafe { core::num::NonZeroUsize::new_unchecked(self.prob.len()) }
+    }
+
+    /// Draw an index in 0..len() with probability proportional to its weight.
+    #[inline]
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let i = rng.random_range(0..self.prob.len());
+        if rng.random::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/* ----- Uniform sampling ----- */
+
+impl Distribution<NzInt> for StandardUniform {
+    /// Uniform over all 2^64 - 1 non-zero values.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NzInt {
+        // 0..u64::MAX misses exactly one bit pattern (-1 as i64), which stands in for 0.
+        let v = rng.random_range(0..u64::MAX) as i64;
+        unsafe { NzInt::new_unchecked(if v == 0 { -1 } else { v }) }
+    }
+}
+
+impl Distribution<NzFloat> for StandardUniform {
+    /// Uniform over (0, 1], the non-zero counterpart of f64's [0, 1).
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NzFloat {
+        let v: f64 = rng.sample(rand::distr::OpenClosed01);
+        unsafe { NzFloat::new_unchecked(v) }
+    }
+}
+
+/// Element types NzUniform supports; each has its own precomputed sampling state.
+pub trait NzUniformElem: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    type State: Copy + core::fmt::Debug;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for crate::nzint::NzInt {}
+    impl Sealed for crate::nzfloat::NzFloat {}
+}
+
+impl NzUniformElem for NzInt {
+    /// Number of non-zero values in range.
+    type State = u64;
+}
+
+impl NzUniformElem for NzFloat {
+    /// Probability of drawing from the negative side.
+    type State = f64;
+}
+
+/// Uniform distribution over [low, high) with zero excluded.
+#[derive(Debug, Clone, Copy)]
+pub struct NzUniform<T: NzUniformElem> {
+    low: T,
+    high: T,
+    state: T::State,
+}
+
+impl NzUniform<NzInt> {
+    /// Returns None if low >= high (the range is empty).
+    #[inline]
+    pub fn new(low: NzInt, high: NzInt) -> Option<Self> {
+        let count = NzRange::new(low, high).remaining();
+        if count == 0 {
+            None
+        } else {
+            Some(NzUniform {
+                low,
+                high,
+                state: count,
+            })
+        }
+    }
+}
+
+impl Distribution<NzInt> for NzUniform<NzInt> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NzInt {
+        let k = rng.random_range(0..self.state);
+        let low = self.low.get();
+        let v = low as i128 + k as i128;
+        // Skip zero: indices at or past the origin shift up by one.
+        let v = if low < 0 && v >= 0 { v + 1 } else { v };
+        debug_assert!(v < self.high.get() as i128);
+        unsafe { NzInt::new_unchecked(v as i64) }
+    }
+}
+
+impl NzUniform<NzFloat> {
+    /// Returns None unless low < high and both are finite.
+    #[inline]
+    pub fn new(low: NzFloat, high: NzFloat) -> Option<Self> {
+        let (l, h) = (low.get(), high.get());
+        if !(l < h && l.is_finite() && h.is_finite()) {
+            return None;
+        }
+        let neg_share = if l < 0.0 && h > 0.0 {
+            // Halved lengths cannot overflow even for -MAX..MAX.
+            (-l / 2.0) / (-l / 2.0 + h / 2.0)
+        } else if h < 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+        Some(NzUniform {
+            low,
+            high,
+            state: neg_share,
+        })
+    }
+}
+
+impl Distribution<NzFloat> for NzUniform<NzFloat> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NzFloat {
+        let (l, h) = (self.low.get(), self.high.get());
+        let (v, positive) = if l > 0.0 || h < 0.0 {
+            // One-sided: [l, h) never contains 0.
+            (l + (h - l) * rng.random::<f64>(), l > 0.0)
+        } else if rng.random_bool(self.state) {
+            // [l, 0): (1 - u) is in (0, 1].
+            (l * (1.0 - rng.random::<f64>()), false)
+        } else {
+            // (0, h)
+            let u: f64 = rng.sample(Open01);
+            (h * u, true)
+        };
+        // Only underflow with subnormal bounds can land on 0.0; snap like the saturating ops.
+        let v = if v == 0.0 {
+            if positive {
+                f64::from_bits(1)
+            } else {
+                -f64::from_bits(1)
+            }
+        } else {
+            v
+        };
+        unsafe { NzFloat::new_unchecked(v) }
+    }
+}
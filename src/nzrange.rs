@@ -0,0 +1,118 @@
+//! nzrange: Integer ranges that skip zero
+//! API:
+//! - NzRange::new(start, end) for [start, end), NzRange::inclusive(start, end) for [start, end]
+//! - Iterator<Item = NzInt> + DoubleEndedIterator + FusedIterator
+//! - ExactSizeIterator on 64-bit targets (the largest range has u64::MAX elements)
+//!
+//! Design choices:
+//! - Bounds are kept inclusive and never zero, so stepping across the origin is -1 -> 1
+//! - An explicit `empty` flag avoids overflow at i64::MIN / i64::MAX
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::nzint::NzInt;
+
+#[derive(Clone)]
+pub struct NzRange {
+    front: i64,
+    back: i64,
+    empty: bool,
+}
+
+impl NzRange {
+    /// Half-open range [start, end) without zero, like `start..end`.
+    #[inline]
+    pub fn new(start: NzInt, end: NzInt) -> NzRange {
+        match end.get().checked_sub(1) {
+            Some(back) => Self::from_bounds(start.get(), back),
+            None => Self::from_bounds(0, -1), // end == i64::MIN: nothing below it
+        }
+    }
+
+    /// Closed range [start, end] without zero, like `start..=end`.
+    #[inline]
+    pub fn inclusive(start: NzInt, end: NzInt) -> NzRange {
+        Self::from_bounds(start.get(), end.get())
+    }
+
+    /// Number of values left, as u64 (exact on every target).
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        if self.empty {
+            return 0;
+        }
+        // i128 because the full range spans 2^64 integers (2^64 - 1 without zero).
+        let span = self.back as i128 - self.front as i128 + 1;
+        let zero = (self.front < 0 && self.back > 0) as i128;
+        (span - zero) as u64
+    }
+
+    fn from_bounds(front: i64, back: i64) -> NzRange {
+        let front = if front == 0 { 1 } else { front };
+        let back = if back == 0 { -1 } else { back };
+        NzRange {
+            front,
+            back,
+            empty: front > back,
+        }
+    }
+}
+
+impl Iterator for NzRange {
+    type Item = NzInt;
+
+    #[inline]
+    fn next(&mut self) -> Option<NzInt> {
+        if self.empty {
+            return None;
+        }
+        let v = self.front;
+        if v == self.back {
+            self.empty = true;
+        } else {
+            self.front = if v == -1 { 1 } else { v + 1 };
+        }
+        // Bounds are never zero.
+        Some(unsafe { NzInt::new_unchecked(v) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for NzRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<NzInt> {
+        if self.empty {
+            return None;
+        }
+        let v = self.back;
+        if v == self.front {
+            self.empty = true;
+        } else {
+            self.back = if v == 1 { -1 } else { v - 1 };
+        }
+        Some(unsafe { NzInt::new_unchecked(v) })
+    }
+}
+
+impl FusedIterator for NzRange {}
+
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for NzRange {}
+
+impl fmt::Debug for NzRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.empty {
+            f.write_str("NzRange(empty)")
+        } else {
+            write!(f, "NzRange({}..={})", self.front, self.back)
+        }
+    }
+}
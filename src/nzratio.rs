@@ -0,0 +1,189 @@
+//! nzratio: Non-zero rational number
+//! Invariants:
+//! - Numerator and denominator are non-zero NzInt
+//! - Always normalized: denominator > 0 and gcd(|numer|, denom) == 1
+//!
+//! API:
+//! - NzRatio::new(numer, denom) -> Result<Self, NzError>
+//! - numer(), denom(), checked_add/sub/mul/div, checked_recip, checked_neg
+//! - to_float() -> NzFloat, From<NzInt>
+//!
+//! Design choices:
+//! - Intermediates are computed in i128, so only the final reduced value can overflow
+//! - Normalized form is unique, so Eq/Hash are structural; Ord cross-multiplies
+//! - checked_div never needs a zero-divisor path: the divisor's numerator is non-zero
+
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::{NzError, NzInt};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NzRatio {
+    numer: NzInt,
+    denom: NzInt,
+}
+
+impl NzRatio {
+    /// Create numer/denom in lowest terms. Err(Overflow) if the normalized
+    /// value does not fit (e.g., 1 / i64::MIN needs denominator 2^63).
+    #[inline]
+    pub fn new(numer: NzInt, denom: NzInt) -> Result<NzRatio, NzError> {
+        Self::reduce(numer.get() as i128, denom.get() as i128)
+    }
+
+    /// The numerator (carries the sign).
+    #[inline]
+    pub fn numer(self) -> NzInt {
+        self.numer
+    }
+
+    /// The denominator (always positive).
+    #[inline]
+    pub fn denom(self) -> NzInt {
+        self.denom
+    }
+
+    /// Checked addition. Err(ZeroResult) if the sum is zero, Err(Overflow) if it does not fit.
+    #[inline]
+    pub fn checked_add(self, rhs: NzRatio) -> Result<NzRatio, NzError> {
+        let (a, b, c, d) = self.widen(rhs);
+        Self::reduce(a * d + c * b, b * d)
+    }
+
+    /// Checked subtraction. Err(ZeroResult) if the difference is zero, Err(Overflow) if it does not fit.
+    #[inline]
+    pub fn checked_sub(self, rhs: NzRatio) -> Result<NzRatio, NzError> {
+        let (a, b, c, d) = self.widen(rhs);
+        Self::reduce(a * d - c * b, b * d)
+    }
+
+    /// Checked multiplication. Err(Overflow) if the product does not fit.
+    #[inline]
+    pub fn checked_mul(self, rhs: NzRatio) -> Result<NzRatio, NzError> {
+        let (a, b, c, d) = self.widen(rhs);
+        Self::reduce(a * c, b * d)
+    }
+
+    /// Checked division. Err(Overflow) if the quotient does not fit.
+    #[inline]
+    pub fn checked_div(self, rhs: NzRatio) -> Result<NzRatio, NzError> {
+        let (a, b, c, d) = self.widen(rhs);
+        Self::reduce(a * d, b * c)
+    }
+
+    /// Reciprocal. Err(Overflow) only for a numerator of i64::MIN.
+    #[inline]
+    pub fn checked_recip(self) -> Result<NzRatio, NzError> {
+        Self::reduce(self.denom.get() as i128, self.numer.get() as i128)
+    }
+
+    /// Negation. Err(Overflow) only for a numerator of i64::MIN.
+    #[inline]
+    pub fn checked_neg(self) -> Result<NzRatio, NzError> {
+        let numer = self.numer.checked_neg().map_err(|_| NzError::Overflow)?;
+        Ok(NzRatio {
+            numer,
+            denom: self.denom,
+        })
+    }
+
+    /// Convert to NzFloat (nearest f64 quotient). Total: |numer / denom| >= 1 / i64::MAX,
+    /// far above the f64 subnormal range.
+    #[inline]
+    pub fn to_float(self) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(self.numer.get() as f64 / self.denom.get() as f64) }
+    }
+
+    #[inline]
+    fn widen(self, rhs: NzRatio) -> (i128, i128, i128, i128) {
+        (
+            self.numer.get() as i128,
+            self.denom.get() as i128,
+            rhs.numer.get() as i128,
+            rhs.denom.get() as i128,
+        )
+    }
+
+    /// Normalize n/d (d != 0) into lowest terms with a positive denominator.
+    fn reduce(n: i128, d: i128) -> Result<NzRatio, NzError> {
+        if n == 0 {
+            return Err(NzError::ZeroResult);
+        }
+        let g = gcd(n.unsigned_abs(), d.unsigned_abs()) as i128;
+        // Both operands came from products of i64s, so |n|, |d| < 2^127 and negation cannot overflow.
+        let (n, d) = if d < 0 {
+            (-n / g, -d / g)
+        } else {
+            (n / g, d / g)
+        };
+        let to_nz = |v: i128| {
+            i64::try_from(v)
+                .ok()
+                .and_then(NzInt::new)
+                .ok_or(NzError::Overflow)
+        };
+        Ok(NzRatio {
+            numer: to_nz(n)?,
+            denom: to_nz(d)?,
+        })
+    }
+}
+
+/// Euclid's algorithm; callers pass a != 0.
+#[inline]
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Debug for NzRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NzRatio")
+            .field("numer", &self.numer.get())
+            .field("denom", &self.denom.get())
+            .finish()
+    }
+}
+
+impl fmt::Display for NzRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+impl PartialOrd for NzRatio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NzRatio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying preserves the order.
+        let (a, b, c, d) = self.widen(*other);
+        (a * d).cmp(&(c * b))
+    }
+}
+
+impl From<NzInt> for NzRatio {
+    #[inline]
+    fn from(v: NzInt) -> NzRatio {
+        NzRatio {
+            numer: v,
+            denom: NzInt::one(),
+        }
+    }
+}
+
+impl From<NzRatio> for NzFloat {
+    #[inline]
+    fn from(v: NzRatio) -> NzFloat {
+        v.to_float()
+    }
+}
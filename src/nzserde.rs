@@ -0,0 +1,54 @@
+//! nzserde: Serde support (feature `serde`)
+//! Format:
+//! - NzInt as i64, NzFloat as f64, nzSign as i8 (1 / -1): plain numbers, transparent to schemas
+//! - Deserializing 0, ±0.0, NaN, or a sign other than ±1 fails with a descriptive error
+
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzsign::nzSign;
+
+impl Serialize for NzInt {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_i64(self.get())
+    }
+}
+
+impl<'de> Deserialize<'de> for NzInt {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let v = i64::deserialize(d)?;
+        NzInt::new(v)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Signed(v), &"a non-zero integer"))
+    }
+}
+
+impl Serialize for NzFloat {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_f64(self.get())
+    }
+}
+
+impl<'de> Deserialize<'de> for NzFloat {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let v = f64::deserialize(d)?;
+        NzFloat::new(v).ok_or_else(|| {
+            D::Error::invalid_value(Unexpected::Float(v), &"a non-zero, non-NaN float")
+        })
+    }
+}
+
+impl Serialize for nzSign {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_i8(self.to_i8())
+    }
+}
+
+impl<'de> Deserialize<'de> for nzSign {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let v = i8::deserialize(d)?;
+        nzSign::from_i8(v)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Signed(v.into()), &"1 or -1"))
+    }
+}
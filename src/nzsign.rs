@@ -1,3 +1,9 @@
+use core::hash::{Hash, Hasher};
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::{NzError, NzInt};
+
+#[allow(non_camel_case_types)]
 #[repr(i8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum nzSign {
@@ -10,9 +16,10 @@ impl nzSign {
     #[inline] pub fn is_false(self) -> bool { matches!(self, nzSign::Neg) }
 
     // Logical NOT (stay in ±1 domain)
+    #[allow(clippy::should_implement_trait)]
     #[inline] pub fn not(self) -> Self { if self.is_true() { nzSign::Neg } else { nzSign::Pos } }
 
-    // AND/OR implemented as min/max semantics; short-circuiting should be handled at VM instruction level
+    // AND/OR implemented as min/max semantics; short-circuiting is done at VM instruction level (vm::Instr::JumpIfPos)
     #[inline] pub fn and(self, rhs: nzSign) -> nzSign {
         // truth table: (Pos,Pos) -> Pos; otherwise Neg
         if self.is_false() { nzSign::Neg } else { rhs }
@@ -27,13 +34,202 @@ impl nzSign {
         if self == rhs { nzSign::Neg } else { nzSign::Pos }
     }
 
+    // Negated AND/OR
+    #[inline]
+    pub fn nand(self, rhs: nzSign) -> nzSign {
+        self.and(rhs).not()
+    }
+    #[inline]
+    pub fn nor(self, rhs: nzSign) -> nzSign {
+        self.or(rhs).not()
+    }
+
+    // Material implication: Neg only for (Pos, Neg)
+    #[inline]
+    pub fn implies(self, rhs: nzSign) -> nzSign {
+        self.not().or(rhs)
+    }
+
+    // Three-input majority vote; equals the sign of a + b + c, which is never zero
+    #[inline]
+    pub fn majority(a: nzSign, b: nzSign, c: nzSign) -> nzSign {
+        if a.to_i8() + b.to_i8() + c.to_i8() > 0 {
+            nzSign::Pos
+        } else {
+            nzSign::Neg
+        }
+    }
+
     // Conversion to/from i8/i64 (for serialization/FFI)
     #[inline] pub fn to_i8(self) -> i8 { self as i8 }
     #[inline] pub fn from_i8(v: i8) -> Option<nzSign> {
         match v { 1 => Some(nzSign::Pos), -1 => Some(nzSign::Neg), _ => None }
     }
 
+    // Stable byte encoding: the i8 value as one byte (Pos = 0x01, Neg = 0xFF)
+    #[inline]
+    pub fn to_stable_bytes(self) -> [u8; 1] {
+        self.to_i8().to_le_bytes()
+    }
+
     // Conversion to/from Rust bool (for host interop)
     #[inline] pub fn to_bool(self) -> bool { self.is_true() }
     #[inline] pub fn from_bool(b: bool) -> Self { if b { nzSign::Pos } else { nzSign::Neg } }
+
+    // Multiply a value by this sign (Pos keeps it, Neg negates it); see ApplySign for the result type
+    #[inline]
+    pub fn apply_to<T: ApplySign>(self, x: T) -> T::Output {
+        x.apply_sign(self)
+    }
+}
+
+/* ----- Sign-magnitude helpers on the numeric types ----- */
+
+// Values a sign can be applied to; Output is Result where negation can overflow
+pub trait ApplySign {
+    type Output;
+    fn apply_sign(self, sign: nzSign) -> Self::Output;
+}
+
+impl ApplySign for NzInt {
+    type Output = Result<NzInt, NzError>;
+    // Err(DivOverflow) only for i64::MIN with Neg
+    #[inline]
+    fn apply_sign(self, sign: nzSign) -> Self::Output {
+        if sign.is_true() {
+            Ok(self)
+        } else {
+            self.checked_neg()
+        }
+    }
+}
+
+impl ApplySign for NzFloat {
+    type Output = NzFloat;
+    // Total: flipping the sign never produces 0.0 or NaN
+    #[inline]
+    fn apply_sign(self, sign: nzSign) -> NzFloat {
+        if sign.is_true() { self } else { -self }
+    }
+}
+
+impl NzInt {
+    // Sign of the value as nzSign (Pos for > 0, Neg for < 0)
+    #[inline]
+    pub const fn sign(self) -> nzSign {
+        if self.get() > 0 {
+            nzSign::Pos
+        } else {
+            nzSign::Neg
+        }
+    }
+
+    // Same magnitude with the given sign; Err(DivOverflow) only for i64::MIN with Pos
+    #[inline]
+    pub fn with_sign(self, sign: nzSign) -> Result<NzInt, NzError> {
+        if self.sign() == sign {
+            Ok(self)
+        } else {
+            self.checked_neg()
+        }
+    }
+}
+
+impl NzFloat {
+    // Sign bit as nzSign (Pos for > 0.0 and +inf, Neg for < 0.0 and -inf)
+    #[inline]
+    pub const fn sign(self) -> nzSign {
+        if self.get().is_sign_positive() {
+            nzSign::Pos
+        } else {
+            nzSign::Neg
+        }
+    }
+
+    // Same magnitude with the given sign (copysign); total
+    #[inline]
+    pub fn with_sign(self, sign: nzSign) -> NzFloat {
+        if self.sign() == sign { self } else { -self }
+    }
+}
+
+// ±1 with the sign's polarity; total
+impl From<nzSign> for NzInt {
+    #[inline]
+    fn from(s: nzSign) -> NzInt {
+        unsafe { NzInt::new_unchecked(s.to_i8() as i64) }
+    }
+}
+
+impl From<nzSign> for NzFloat {
+    #[inline]
+    fn from(s: nzSign) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(s.to_i8() as f64) }
+    }
+}
+
+// true -> 1, false -> -1 (via nzSign::from_bool); total
+impl From<bool> for NzInt {
+    #[inline]
+    fn from(b: bool) -> NzInt {
+        nzSign::from_bool(b).into()
+    }
+}
+
+impl Hash for nzSign {
+    #[cfg(not(feature = "stable-hash"))]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_i8().hash(state)
+    }
+    #[cfg(feature = "stable-hash")]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_stable_bytes())
+    }
+}
+
+/* ----- Sign application operators (feature `sign-ops`) ----- */
+
+#[cfg(feature = "sign-ops")]
+mod ops {
+    use core::ops::Mul;
+
+    use super::nzSign;
+    use crate::nzfloat::NzFloat;
+    use crate::nzint::NzInt;
+
+    impl Mul<nzSign> for NzInt {
+        type Output = NzInt;
+        // Pos keeps the value, Neg negates it; panics only for i64::MIN * Neg, like -i64::MIN
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: nzSign) -> NzInt {
+            if rhs.is_true() { self } else { -self }
+        }
+    }
+
+    impl Mul<NzInt> for nzSign {
+        type Output = NzInt;
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: NzInt) -> NzInt {
+            rhs * self
+        }
+    }
+
+    impl Mul<nzSign> for NzFloat {
+        type Output = NzFloat;
+        // Total: flipping the sign never produces 0.0 or NaN
+        #[inline]
+        fn mul(self, rhs: nzSign) -> NzFloat {
+            if rhs.is_true() { self } else { -self }
+        }
+    }
+
+    impl Mul<NzFloat> for nzSign {
+        type Output = NzFloat;
+        #[inline]
+        fn mul(self, rhs: NzFloat) -> NzFloat {
+            rhs * self
+        }
+    }
 }
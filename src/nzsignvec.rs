@@ -0,0 +1,180 @@
+//! nzsignvec: Packed vector of nzSign values (needs `alloc`)
+//! Invariants:
+//! - Bit i of word i / 64 is set for Neg and clear for Pos
+//! - words.len() == len.div_ceil(64); bits past len in the last word are zero
+//!
+//! API:
+//! - FromIterator<nzSign>, push, get, len, iter, as_words
+//! - Element-wise and/or/xor/not with nzSign semantics, returning a new vector
+//! - count_pos()/count_neg() (popcount)
+//!
+//! Design choices:
+//! - Neg is the set bit, so an all-Pos vector is all-zero words; sign logic maps to word ops:
+//!   and = OR of Neg bits, or = AND of Neg bits, xor = XNOR (equal signs give Neg)
+//! - Binary ops panic on a length mismatch, like the batch functions
+
+use alloc::vec::Vec;
+
+use crate::nzsign::nzSign;
+
+/// Bit-packed sequence of signs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NzSignVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl NzSignVec {
+    /// Empty vector.
+    #[inline]
+    pub fn new() -> Self {
+        NzSignVec::default()
+    }
+
+    /// Build from packed words; the caller upholds the module invariants.
+    #[inline]
+    pub(crate) fn from_words(words: Vec<u64>, len: usize) -> Self {
+        debug_assert_eq!(words.len(), len.div_ceil(64));
+        let v = NzSignVec { words, len };
+        debug_assert_eq!(v.words.last().map_or(0, |w| w & !v.tail_mask()), 0);
+        v
+    }
+
+    /// Number of signs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if there are no signs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append a sign.
+    #[inline]
+    pub fn push(&mut self, s: nzSign) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        if s.is_false() {
+            self.words[self.len / 64] |= 1 << (self.len % 64);
+        }
+        self.len += 1;
+    }
+
+    /// Sign at `index`, or None if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<nzSign> {
+        if index >= self.len {
+            return None;
+        }
+        let neg = self.words[index / 64] >> (index % 64) & 1 == 1;
+        Some(if neg { nzSign::Neg } else { nzSign::Pos })
+    }
+
+    /// Number of Neg entries.
+    #[inline]
+    pub fn count_neg(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Number of Pos entries.
+    #[inline]
+    pub fn count_pos(&self) -> usize {
+        self.len - self.count_neg()
+    }
+
+    /// Packed words (unused high bits of the last word are zero).
+    #[inline]
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Iterate the signs in order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = nzSign> + ExactSizeIterator + '_ {
+        // get() cannot fail for indices below len.
+        (0..self.len).map(|i| self.get(i).unwrap_or(nzSign::Pos))
+    }
+
+    /// Element-wise nzSign::and (Pos only where both are Pos).
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn and(&self, rhs: &NzSignVec) -> NzSignVec {
+        self.zip_words(rhs, "and", |a, b| a | b)
+    }
+
+    /// Element-wise nzSign::or (Pos where either is Pos).
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn or(&self, rhs: &NzSignVec) -> NzSignVec {
+        self.zip_words(rhs, "or", |a, b| a & b)
+    }
+
+    /// Element-wise nzSign::xor (Pos where the signs differ).
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn xor(&self, rhs: &NzSignVec) -> NzSignVec {
+        self.zip_words(rhs, "xor", |a, b| !(a ^ b))
+    }
+
+    /// Element-wise nzSign::not.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(&self) -> NzSignVec {
+        self.map_words(|w| !w)
+    }
+
+    /// Mask of the valid bits in the last word.
+    #[inline]
+    fn tail_mask(&self) -> u64 {
+        match self.len % 64 {
+            0 => u64::MAX,
+            r => (1 << r) - 1,
+        }
+    }
+
+    fn map_words(&self, f: impl Fn(u64) -> u64) -> NzSignVec {
+        self.with_words(self.words.iter().map(|&w| f(w)).collect())
+    }
+
+    fn zip_words(&self, rhs: &NzSignVec, op: &str, f: impl Fn(u64, u64) -> u64) -> NzSignVec {
+        assert_eq!(self.len, rhs.len, "NzSignVec::{op}: length mismatch");
+        self.with_words(
+            self.words
+                .iter()
+                .zip(&rhs.words)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        )
+    }
+
+    /// Same length, new words; clears the bits past len that not/xor may have set.
+    fn with_words(&self, mut words: Vec<u64>) -> NzSignVec {
+        if let Some(last) = words.last_mut() {
+            *last &= self.tail_mask();
+        }
+        NzSignVec {
+            words,
+            len: self.len,
+        }
+    }
+}
+
+impl FromIterator<nzSign> for NzSignVec {
+    fn from_iter<I: IntoIterator<Item = nzSign>>(iter: I) -> Self {
+        let mut v = NzSignVec::new();
+        for s in iter {
+            v.push(s);
+        }
+        v
+    }
+}
+
+impl Extend<nzSign> for NzSignVec {
+    fn extend<I: IntoIterator<Item = nzSign>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! nzsql: Database driver support (features `sqlx`, `postgres`)
+//! Format:
+//! - NzInt as i64 (BIGINT), NzFloat as f64 (DOUBLE PRECISION); the column types are exactly
+//!   those of i64 / f64 for each driver and database
+//! - Decoding 0 / ±0.0 fails with NzParseError::Zero and NaN with NzParseError::NotANumber,
+//!   so the driver's error names the bad value rather than failing somewhere downstream
+//! - Decoding accepts what NzFloat::new and serde accept: `strict-floats` / `deny-subnormals`
+//!   govern arithmetic results, not stored values
+//!
+//! Design choices:
+//! - sqlx impls are generic over the Database and defer to i64 / f64, so they cover every
+//!   backend enabled in the application (Postgres, MySQL, SQLite) without features here
+//! - The postgres impls use postgres-types, the crate both `postgres` and `tokio-postgres` re-export
+
+use std::boxed::Box;
+use std::error::Error;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nzparse::NzParseError;
+
+/// The boxed error type both drivers use.
+type DecodeError = Box<dyn Error + Send + Sync>;
+
+#[inline]
+fn int_from(v: i64) -> Result<NzInt, DecodeError> {
+    Ok(NzInt::new(v).ok_or(NzParseError::Zero)?)
+}
+
+/// Accepts exactly what NzFloat::new (and serde) accepts, ±inf and subnormals included
+/// whatever the features, so a value stored through one format decodes through the other.
+#[inline]
+fn float_from(v: f64) -> Result<NzFloat, DecodeError> {
+    if v.is_nan() {
+        return Err(NzParseError::NotANumber.into());
+    }
+    Ok(NzFloat::new(v).ok_or(NzParseError::Zero)?)
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    use super::{float_from, int_from};
+    use crate::nzfloat::NzFloat;
+    use crate::nzint::NzInt;
+
+    macro_rules! sqlx_impl {
+        ($t:ty, $raw:ty, $from:ident) => {
+            impl<DB: Database> Type<DB> for $t
+            where
+                $raw: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <$raw as Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <$raw as Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB: Database> Encode<'q, DB> for $t
+            where
+                $raw: Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut DB::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    self.get().encode_by_ref(buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    self.get().size_hint()
+                }
+            }
+
+            impl<'r, DB: Database> Decode<'r, DB> for $t
+            where
+                $raw: Decode<'r, DB>,
+            {
+                fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    $from(<$raw as Decode<'r, DB>>::decode(value)?)
+                }
+            }
+        };
+    }
+
+    sqlx_impl!(NzInt, i64, int_from);
+    sqlx_impl!(NzFloat, f64, float_from);
+}
+
+#[cfg(feature = "postgres")]
+mod postgres_impls {
+    use std::boxed::Box;
+    use std::error::Error;
+
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+
+    use super::{float_from, int_from};
+    use crate::nzfloat::NzFloat;
+    use crate::nzint::NzInt;
+
+    macro_rules! postgres_impl {
+        ($t:ty, $raw:ty, $from:ident) => {
+            impl<'a> FromSql<'a> for $t {
+                fn from_sql(
+                    ty: &Type,
+                    raw: &'a [u8],
+                ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                    $from(<$raw as FromSql>::from_sql(ty, raw)?)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <$raw as FromSql>::accepts(ty)
+                }
+            }
+
+            impl ToSql for $t {
+                fn to_sql(
+                    &self,
+                    ty: &Type,
+                    out: &mut BytesMut,
+                ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                    self.get().to_sql(ty, out)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <$raw as ToSql>::accepts(ty)
+                }
+
+                to_sql_checked!();
+            }
+        };
+    }
+
+    postgres_impl!(NzInt, i64, int_from);
+    postgres_impl!(NzFloat, f64, float_from);
+}
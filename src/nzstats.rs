@@ -0,0 +1,216 @@
+//! nzstats: Aggregates over non-empty collections of nz values
+//! API:
+//! - NonEmptySlice<T>: a slice with at least one element (NonEmptySlice::new(&[T]) -> Option<&Self>)
+//! - parallel_sum(&NonEmptySlice<NzFloat>) -> Result<NzFloat, NzfError>: 1 / Σ(1/xᵢ)
+//! - NzProb: probability in (0, 1]
+//! - normalize(&NonEmptySlice<NzFloat>) -> Result<Vec<NzProb>, NzError> (needs `alloc`)
+//! - softmax(&NonEmptySlice<NzFloat>) -> Vec<NzProb> (needs `alloc` and `std` or `libm`)
+//!
+//! Design choices:
+//! - Emptiness is ruled out by the argument type, so aggregates need no "empty input" error
+//! - Each 1/xᵢ is a division by a non-zero value; only cancellation in the sum can fail
+//! - normalize/softmax rescale by the maximum before summing, so the denominator is in [1, n];
+//!   a probability that underflows snaps to the smallest subnormal, as in the saturating ops
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::num::NonZeroUsize;
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use crate::math;
+use crate::nzfloat::{NzFloat, NzfError};
+#[cfg(feature = "alloc")]
+use crate::nzint::NzError;
+#[cfg(any(feature = "alloc", feature = "rand"))]
+use crate::nzpositive::PzFloat;
+
+/// A slice that holds at least one element.
+#[repr(transparent)]
+pub struct NonEmptySlice<T>([T]);
+
+impl<T> NonEmptySlice<T> {
+    /// View a slice as non-empty. Returns None if it is empty.
+    #[inline]
+    pub fn new(s: &[T]) -> Option<&NonEmptySlice<T>> {
+        if s.is_empty() {
+            return None;
+        }
+        // repr(transparent) over [T], so the fat pointer layout is identical.
+        Some(unsafe { &*(s as *const [T] as *const NonEmptySlice<T>) })
+    }
+
+    /// The underlying slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// The first element; total.
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Number of elements (at least 1).
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+}
+
+impl<T> core::ops::Deref for NonEmptySlice<T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+/// Parallel combination 1 / Σ(1/xᵢ) (resistors in parallel, springs in series, thin lenses).
+/// Returns:
+/// - Err(ZeroResult) if the reciprocals cancel (e.g., 2 and -2), since 1/0 has no sign,
+///   or if the result underflows (a subnormal xᵢ makes the sum infinite).
+/// - Err(NotANumber) if the reciprocals overflow to +inf and -inf.
+#[inline]
+pub fn parallel_sum(values: &NonEmptySlice<NzFloat>) -> Result<NzFloat, NzfError> {
+    let sum: f64 = values.iter().map(|x| x.get().recip()).sum();
+    let err = if sum.is_nan() {
+        NzfError::NotANumber
+    } else {
+        NzfError::ZeroResult
+    };
+    let sum = NzFloat::new(sum).ok_or(err)?;
+    NzFloat::one().checked_div(sum)
+}
+
+/// A probability in (0, 1]; never 0 and never NaN.
+#[derive(Clone, Copy)]
+pub struct NzProb(f64);
+
+impl NzProb {
+    /// Create from f64. Returns None unless 0 < p <= 1.
+    #[inline]
+    pub fn new(p: f64) -> Option<Self> {
+        if p > 0.0 && p <= 1.0 {
+            Some(NzProb(p))
+        } else {
+            None
+        }
+    }
+
+    /// Get the inner f64.
+    #[inline]
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Probability from a share of a total (0 <= share <= total); 0 snaps to the smallest subnormal.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn from_share(share: f64, total: f64) -> NzProb {
+        let p = (share / total).min(1.0);
+        NzProb(if p > 0.0 { p } else { f64::from_bits(1) })
+    }
+}
+
+/// Scale every value into [0, 1] relative to the largest and normalize the shares.
+/// Returns Err(Domain) if the values do not all have the same sign.
+#[cfg(feature = "alloc")]
+pub fn normalize(values: &NonEmptySlice<NzFloat>) -> Result<Vec<NzProb>, NzError> {
+    let sign = values.first().sign();
+    if values.iter().any(|x| x.sign() != sign) {
+        return Err(NzError::Domain);
+    }
+    let mags: Vec<PzFloat> = values.iter().map(|x| x.to_sign_magnitude().1).collect();
+    // Non-empty, so a maximum exists.
+    let scale = max_weight(&mags).unwrap_or(1.0);
+    let total: f64 = mags.iter().map(|&m| scaled(m, scale)).sum();
+    Ok(mags
+        .iter()
+        .map(|&m| NzProb::from_share(scaled(m, scale), total))
+        .collect())
+}
+
+/// Numerically stable softmax: exp(xᵢ - max) / Σ exp(xⱼ - max). If the maximum is infinite,
+/// the entries equal to it share all of the probability.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub fn softmax(values: &NonEmptySlice<NzFloat>) -> Vec<NzProb> {
+    let max = values
+        .iter()
+        .map(|x| x.get())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let weight = |x: f64| {
+        if max.is_infinite() {
+            if x == max { 1.0 } else { 0.0 }
+        } else {
+            math::exp(x - max)
+        }
+    };
+    // The maximum contributes exactly 1.0, so total >= 1.
+    let total: f64 = values.iter().map(|x| weight(x.get())).sum();
+    values
+        .iter()
+        .map(|x| NzProb::from_share(weight(x.get()), total))
+        .collect()
+}
+
+/// Largest weight, or None for an empty slice.
+#[cfg(any(feature = "alloc", feature = "rand"))]
+#[inline]
+pub(crate) fn max_weight(weights: &[PzFloat]) -> Option<f64> {
+    weights.iter().map(|w| w.get()).reduce(f64::max)
+}
+
+/// Weight relative to the maximum, in [0, 1]; infinite weights map to 1 and finite ones to 0
+/// when the maximum is +inf.
+#[cfg(any(feature = "alloc", feature = "rand"))]
+#[inline]
+pub(crate) fn scaled(w: PzFloat, scale: f64) -> f64 {
+    if scale.is_infinite() {
+        if w.get().is_infinite() { 1.0 } else { 0.0 }
+    } else {
+        w.get() / scale
+    }
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Debug for NzProb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NzProb").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for NzProb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq for NzProb {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl Eq for NzProb {}
+
+impl PartialOrd for NzProb {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NzProb {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl From<NzProb> for NzFloat {
+    #[inline]
+    fn from(p: NzProb) -> NzFloat {
+        unsafe { NzFloat::new_unchecked(p.0) }
+    }
+}
@@ -0,0 +1,41 @@
+//! nzvalidate: Validating large raw buffers in place
+//! API:
+//! - validate_chunked(&[i64], chunk: NzUsize, on_progress) -> Result<&[NzInt], Vec<usize>>
+//!
+//! Design choices:
+//! - Success borrows the input as &[NzInt] (NzInt is repr(transparent)), so nothing is copied
+//! - Failure lists every zero index, unlike ZeroReport which caps them; the caller asked for all
+//! - The chunk size is an NzUsize, so a zero-length chunk (an infinite loop) cannot be requested
+
+use alloc::vec::Vec;
+
+use crate::nzint::NzInt;
+use crate::nzwidth::NzUsize;
+
+/// Validate `values` chunk by chunk, calling `on_progress` with the number of elements
+/// checked so far after each chunk.
+/// Returns the same memory viewed as &[NzInt] if no value is zero, otherwise the indices
+/// of all zeros in ascending order.
+pub fn validate_chunked(
+    values: &[i64],
+    chunk: NzUsize,
+    mut on_progress: impl FnMut(usize),
+) -> Result<&[NzInt], Vec<usize>> {
+    let mut zeros = Vec::new();
+    let mut done = 0;
+    for part in values.chunks(chunk.get()) {
+        zeros.extend(
+            part.iter()
+                .enumerate()
+                .filter(|&(_, &v)| v == 0)
+                .map(|(i, _)| done + i),
+        );
+        done += part.len();
+        on_progress(done);
+    }
+    if !zeros.is_empty() {
+        return Err(zeros);
+    }
+    // Every element is non-zero and NzInt has the same layout as i64.
+    Ok(unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<NzInt>(), values.len()) })
+}
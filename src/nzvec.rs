@@ -0,0 +1,233 @@
+//! nzvec: Non-zero 2D/3D vectors over f64
+//! Invariants:
+//! - The vector is never the zero vector; individual components may be ±0.0
+//! - Components are finite (no NaN, no ±inf)
+//!
+//! API:
+//! - NzVec2::new(x, y) / NzVec3::new(x, y, z) -> Option<Self>, x()/y()/z(), to_array(), TryFrom<[f64; N]>
+//! - checked_add/sub -> Result<Self, NzfError>: Err(ZeroResult) if the vectors cancel
+//! - checked_scale(NzFloat): Err(ZeroResult) on underflow, Err(Infinite) on overflow
+//! - dot -> Result<NzFloat, NzfError>: Err(ZeroResult) for orthogonal vectors
+//! - NzVec3::cross / NzVec2::perp_dot: Err(ZeroResult) for parallel vectors
+//! - norm() -> NzFloat, normalize() -> Self (need the `std` or `libm` feature); Neg
+//!
+//! Design choices:
+//! - Finite components make normalize() total: dividing by the largest |component| first
+//!   brings every component into [-1, 1] with one at ±1, so the length is in [1, √3] and
+//!   neither overflows nor underflows, and the normalized vector keeps a component >= 1/√3
+//! - Components are always finite; with `deny-subnormals` a subnormal component also fails
+
+use core::fmt;
+use core::ops::Neg;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::nzfloat::{NzFloat, NzfError};
+
+/// Validate raw components against the vector invariant, then the checked-op rules.
+#[inline]
+fn check<const N: usize>(v: [f64; N]) -> Result<[f64; N], NzfError> {
+    let nan = v.iter().any(|c| c.is_nan());
+    // Finite components are part of the invariant, so ±inf fails whatever the features.
+    if !nan && v.iter().any(|c| c.is_infinite()) {
+        return Err(NzfError::Infinite);
+    }
+    let zero = v.iter().all(|&c| c == 0.0);
+    match NzfError::classify(nan, zero, false, v.iter().any(|c| c.is_subnormal())) {
+        None => Ok(v),
+        Some(e) => Err(e),
+    }
+}
+
+/// Shared API; the struct holds the components as `v: [f64; N]`.
+macro_rules! nz_vec {
+    ($name:ident, $n:literal) => {
+        impl $name {
+            /// Components as an array.
+            #[inline]
+            pub fn to_array(self) -> [f64; $n] {
+                self.v
+            }
+
+            /// Checked addition. Err(ZeroResult) if the vectors cancel, Err(Infinite) on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Result<Self, NzfError> {
+                check(core::array::from_fn(|i| self.v[i] + rhs.v[i])).map(|v| $name { v })
+            }
+
+            /// Checked subtraction. Err(ZeroResult) if self == rhs, Err(Infinite) on overflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Result<Self, NzfError> {
+                check(core::array::from_fn(|i| self.v[i] - rhs.v[i])).map(|v| $name { v })
+            }
+
+            /// Checked scalar multiplication. Err(ZeroResult) if every component underflows,
+            /// Err(Infinite) if one overflows (or k is ±inf).
+            #[inline]
+            pub fn checked_scale(self, k: NzFloat) -> Result<Self, NzfError> {
+                check(self.v.map(|c| c * k.get())).map(|v| $name { v })
+            }
+
+            /// Dot product. Err(ZeroResult) if the vectors are orthogonal (or it underflows).
+            #[inline]
+            pub fn dot(self, rhs: Self) -> Result<NzFloat, NzfError> {
+                NzFloat::check(self.v.iter().zip(rhs.v).map(|(a, b)| a * b).sum())
+            }
+
+            /// Euclidean length; total, +inf if it exceeds f64::MAX.
+            #[cfg(any(feature = "std", feature = "libm"))]
+            #[inline]
+            pub fn norm(self) -> NzFloat {
+                let (m, unit) = self.scaled();
+                // m > 0 and the scaled length is in [1, √3].
+                unsafe { NzFloat::new_unchecked(m * math::sqrt(unit.iter().map(|c| c * c).sum())) }
+            }
+
+            /// Unit vector in the same direction; total.
+            #[cfg(any(feature = "std", feature = "libm"))]
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let (_, unit) = self.scaled();
+                let len = math::sqrt(unit.iter().map(|c| c * c).sum());
+                // A tiny component can come out subnormal; with `deny-subnormals` it flushes to ±0.0,
+                // which cannot empty the vector since the largest component stays >= 1/√3.
+                let flush = |c: f64| {
+                    if cfg!(feature = "deny-subnormals") && c.is_subnormal() {
+                        0.0f64.copysign(c)
+                    } else {
+                        c
+                    }
+                };
+                $name {
+                    v: unit.map(|c| flush(c / len)),
+                }
+            }
+
+            /// Largest |component| and the components divided by it.
+            #[cfg(any(feature = "std", feature = "libm"))]
+            #[inline]
+            fn scaled(self) -> (f64, [f64; $n]) {
+                let m = self.v.iter().fold(0.0f64, |m, c| m.max(c.abs()));
+                (m, self.v.map(|c| c / m))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+            #[inline]
+            fn neg(self) -> $name {
+                $name {
+                    v: self.v.map(|c| -c),
+                }
+            }
+        }
+
+        impl TryFrom<[f64; $n]> for $name {
+            type Error = NzfError;
+            #[inline]
+            fn try_from(v: [f64; $n]) -> Result<Self, Self::Error> {
+                check(v).map(|v| $name { v })
+            }
+        }
+
+        impl From<$name> for [f64; $n] {
+            #[inline]
+            fn from(x: $name) -> Self {
+                x.v
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!(stringify!($name), "{:?}"), self.v)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("(")?;
+                for (i, c) in self.v.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    fmt::Display::fmt(c, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+/// Non-zero 2D vector.
+#[derive(Clone, Copy, PartialEq)]
+pub struct NzVec2 {
+    v: [f64; 2],
+}
+
+/// Non-zero 3D vector.
+#[derive(Clone, Copy, PartialEq)]
+pub struct NzVec3 {
+    v: [f64; 3],
+}
+
+nz_vec!(NzVec2, 2);
+nz_vec!(NzVec3, 3);
+
+impl NzVec2 {
+    /// (x, y); None for the zero vector or a NaN/infinite component.
+    #[inline]
+    pub fn new(x: f64, y: f64) -> Option<Self> {
+        check([x, y]).ok().map(|v| NzVec2 { v })
+    }
+
+    /// x component (may be ±0.0).
+    #[inline]
+    pub fn x(self) -> f64 {
+        self.v[0]
+    }
+
+    /// y component (may be ±0.0).
+    #[inline]
+    pub fn y(self) -> f64 {
+        self.v[1]
+    }
+
+    /// Scalar 2D cross product x1*y2 - y1*x2. Err(ZeroResult) if the vectors are parallel.
+    #[inline]
+    pub fn perp_dot(self, rhs: NzVec2) -> Result<NzFloat, NzfError> {
+        NzFloat::check(self.v[0] * rhs.v[1] - self.v[1] * rhs.v[0])
+    }
+}
+
+impl NzVec3 {
+    /// (x, y, z); None for the zero vector or a NaN/infinite component.
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64) -> Option<Self> {
+        check([x, y, z]).ok().map(|v| NzVec3 { v })
+    }
+
+    /// x component (may be ±0.0).
+    #[inline]
+    pub fn x(self) -> f64 {
+        self.v[0]
+    }
+
+    /// y component (may be ±0.0).
+    #[inline]
+    pub fn y(self) -> f64 {
+        self.v[1]
+    }
+
+    /// z component (may be ±0.0).
+    #[inline]
+    pub fn z(self) -> f64 {
+        self.v[2]
+    }
+
+    /// Cross product. Err(ZeroResult) if the vectors are parallel, Err(Infinite) on overflow.
+    #[inline]
+    pub fn cross(self, rhs: NzVec3) -> Result<NzVec3, NzfError> {
+        let ([a1, a2, a3], [b1, b2, b3]) = (self.v, rhs.v);
+        check([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1]).map(|v| NzVec3 { v })
+    }
+}
@@ -0,0 +1,412 @@
+//! nzwidth: Non-zero integers for every primitive width
+//! Invariants:
+//! - Value is always non-zero, exactly as for nzint
+//! - Arithmetic wraps like the VM integer ops and only errors when the result is zero
+//!
+//! Design choices:
+//! - Macro-generated family NzI8..NzI128 / NzU8..NzU128 (+ NzIsize / NzUsize) backed by core::num::NonZero*
+//! - NzI64 is NzInt itself, so the i64 type keeps its full API
+//! - Lossless width changes are From; lossy ones are TryFrom returning NzError::OutOfRange
+//! - widen::<T>() / try_narrow::<T>() spell the same conversions explicitly, gated by the
+//!   WidensTo / NarrowsTo marker traits so a lossy pair cannot be widened by mistake
+//! - NzInt also converts directly to and from core's NonZero* types, with the same From/TryFrom split
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+use crate::nzint::{NzError, NzInt};
+
+/// The 64-bit signed member of the family.
+pub type NzI64 = NzInt;
+
+/// Shared API for every width (mirrors NzInt).
+macro_rules! nz_int_type {
+    ($(#[$meta:meta])* $name:ident, $prim:ty, $nz:ty) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy)]
+        pub struct $name($nz);
+
+        impl $name {
+            #[doc = concat!("Create a new ", stringify!($name), ". Returns None if v == 0.")]
+            #[inline]
+            pub const fn new(v: $prim) -> Option<Self> {
+                // Option::map is not const
+                match <$nz>::new(v) {
+                    Some(nz) => Some($name(nz)),
+                    None => None,
+                }
+            }
+
+            #[doc = concat!("Create a new ", stringify!($name), " without checking. Caller must guarantee v != 0.")]
+            /// # Safety
+            /// Passing 0 is UB for the backing NonZero type and breaks invariants.
+            #[inline]
+            pub const unsafe fn new_unchecked(v: $prim) -> Self {
+                $name(unsafe { <$nz>::new_unchecked(v) })
+            }
+
+            #[doc = concat!("Get the inner ", stringify!($prim), ".")]
+            #[inline]
+            pub const fn get(self) -> $prim {
+                self.0.get()
+            }
+
+            /// Checked (wrapping) addition. Returns Err(ZeroResult) if the sum is zero.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Result<Self, NzError> {
+                Self::new(self.get().wrapping_add(rhs.get())).ok_or(NzError::ZeroResult)
+            }
+
+            /// Checked (wrapping) subtraction. Returns Err(ZeroResult) if the difference is zero.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Result<Self, NzError> {
+                Self::new(self.get().wrapping_sub(rhs.get())).ok_or(NzError::ZeroResult)
+            }
+
+            /// Checked (wrapping) multiplication. Returns Err(ZeroResult) if the product is zero.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Result<Self, NzError> {
+                Self::new(self.get().wrapping_mul(rhs.get())).ok_or(NzError::ZeroResult)
+            }
+
+            /// Checked division (truncates toward zero).
+            /// Returns:
+            /// - Err(ZeroResult) if quotient is zero.
+            /// - Err(DivOverflow) for MIN / -1 on signed widths.
+            #[inline]
+            pub fn checked_div(self, rhs: Self) -> Result<Self, NzError> {
+                // Divisor is guaranteed non-zero, so None can only mean overflow.
+                let q = self.get().checked_div(rhs.get()).ok_or(NzError::DivOverflow)?;
+                Self::new(q).ok_or(NzError::ZeroResult)
+            }
+
+            /// Construct 1.
+            #[inline]
+            pub const fn one() -> Self {
+                unsafe { Self::new_unchecked(1) }
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.get())
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
+        impl From<$nz> for $name {
+            #[inline]
+            fn from(nz: $nz) -> Self {
+                $name(nz)
+            }
+        }
+
+        impl From<$name> for $nz {
+            #[inline]
+            fn from(v: $name) -> Self {
+                v.0
+            }
+        }
+
+        impl TryFrom<$prim> for $name {
+            type Error = NzError;
+            #[inline]
+            fn try_from(v: $prim) -> Result<Self, Self::Error> {
+                Self::new(v).ok_or(NzError::ZeroResult)
+            }
+        }
+    };
+}
+
+/// Sign-dependent API for signed widths.
+macro_rules! nz_signed_ops {
+    ($name:ident) => {
+        impl $name {
+            /// Checked negation. Returns Err(DivOverflow) when negating MIN.
+            #[inline]
+            pub fn checked_neg(self) -> Result<Self, NzError> {
+                let r = self.get().checked_neg().ok_or(NzError::DivOverflow)?;
+                Ok(unsafe { Self::new_unchecked(r) })
+            }
+
+            /// Absolute value. Returns Err(DivOverflow) for MIN.
+            #[inline]
+            pub fn checked_abs(self) -> Result<Self, NzError> {
+                let r = self.get().checked_abs().ok_or(NzError::DivOverflow)?;
+                Ok(unsafe { Self::new_unchecked(r) })
+            }
+
+            /// Sign of the value: +1 for positive, -1 for negative.
+            #[inline]
+            pub const fn signum(self) -> Self {
+                if self.get() > 0 {
+                    Self::one()
+                } else {
+                    Self::neg_one()
+                }
+            }
+
+            /// Construct -1.
+            #[inline]
+            pub const fn neg_one() -> Self {
+                unsafe { Self::new_unchecked(-1) }
+            }
+        }
+    };
+}
+
+/// Sign-dependent API for unsigned widths.
+macro_rules! nz_unsigned_ops {
+    ($name:ident) => {
+        impl $name {
+            /// Checked negation. Always Err(OutOfRange): the negation of a non-zero unsigned
+            /// value is negative, which the type cannot represent.
+            #[inline]
+            pub fn checked_neg(self) -> Result<Self, NzError> {
+                Err(NzError::OutOfRange)
+            }
+
+            /// Absolute value; the identity for unsigned widths.
+            #[inline]
+            pub fn checked_abs(self) -> Result<Self, NzError> {
+                Ok(self)
+            }
+        }
+    };
+}
+
+nz_int_type!(
+    /// Non-zero 8-bit signed integer.
+    NzI8, i8, NonZeroI8
+);
+nz_int_type!(
+    /// Non-zero 16-bit signed integer.
+    NzI16, i16, NonZeroI16
+);
+nz_int_type!(
+    /// Non-zero 32-bit signed integer.
+    NzI32, i32, NonZeroI32
+);
+nz_int_type!(
+    /// Non-zero 128-bit signed integer.
+    NzI128, i128, NonZeroI128
+);
+nz_int_type!(
+    /// Non-zero pointer-sized signed integer.
+    NzIsize, isize, NonZeroIsize
+);
+nz_int_type!(
+    /// Non-zero 8-bit unsigned integer.
+    NzU8, u8, NonZeroU8
+);
+nz_int_type!(
+    /// Non-zero 16-bit unsigned integer.
+    NzU16, u16, NonZeroU16
+);
+nz_int_type!(
+    /// Non-zero 32-bit unsigned integer.
+    NzU32, u32, NonZeroU32
+);
+nz_int_type!(
+    /// Non-zero 64-bit unsigned integer.
+    NzU64, u64, NonZeroU64
+);
+nz_int_type!(
+    /// Non-zero 128-bit unsigned integer.
+    NzU128, u128, NonZeroU128
+);
+nz_int_type!(
+    /// Non-zero pointer-sized unsigned integer (lengths, chunk sizes).
+    NzUsize, usize, NonZeroUsize
+);
+
+nz_signed_ops!(NzI8);
+nz_signed_ops!(NzI16);
+nz_signed_ops!(NzI32);
+nz_signed_ops!(NzI128);
+nz_signed_ops!(NzIsize);
+nz_unsigned_ops!(NzU8);
+nz_unsigned_ops!(NzU16);
+nz_unsigned_ops!(NzU32);
+nz_unsigned_ops!(NzU64);
+nz_unsigned_ops!(NzU128);
+nz_unsigned_ops!(NzUsize);
+
+/* ----- Conversions between widths ----- */
+
+/// Marker: Self converts to T without loss (the From pairs of this family).
+pub trait WidensTo<T>: Into<T> {}
+
+/// Marker: Self converts to T only if the value fits (the TryFrom pairs of this family).
+pub trait NarrowsTo<T>: TryInto<T, Error = NzError> {}
+
+/// Explicit, type-checked width conversion methods.
+macro_rules! nz_width_methods {
+    ($($name:ident),+) => {$(
+        impl $name {
+            /// Lossless conversion to a wider (or sign-compatible) width.
+            #[inline]
+            pub fn widen<T>(self) -> T
+            where
+                Self: WidensTo<T>,
+            {
+                self.into()
+            }
+
+            /// Checked conversion to a narrower (or sign-incompatible) width.
+            /// Returns Err(OutOfRange) if the value does not fit.
+            #[inline]
+            pub fn try_narrow<T>(self) -> Result<T, NzError>
+            where
+                Self: NarrowsTo<T>,
+            {
+                self.try_into()
+            }
+        }
+    )+};
+}
+
+nz_width_methods!(
+    NzI8, NzI16, NzI32, NzInt, NzI128, NzIsize, NzU8, NzU16, NzU32, NzU64, NzU128, NzUsize
+);
+
+/// Lossless widening: a non-zero value stays non-zero.
+macro_rules! nz_from {
+    ($from:ident => $($to:ident),+) => {$(
+        impl From<$from> for $to {
+            #[inline]
+            fn from(v: $from) -> Self {
+                unsafe { $to::new_unchecked(v.get().into()) }
+            }
+        }
+
+        impl WidensTo<$to> for $from {}
+    )+};
+}
+
+/// Lossy conversion: fails with OutOfRange when the value does not fit.
+macro_rules! nz_try_from {
+    ($from:ident => $($to:ident),+) => {$(
+        impl TryFrom<$from> for $to {
+            type Error = NzError;
+            #[inline]
+            fn try_from(v: $from) -> Result<Self, Self::Error> {
+                let r = v.get().try_into().map_err(|_| NzError::OutOfRange)?;
+                // In range conversions preserve the value, so it is still non-zero.
+                Ok(unsafe { $to::new_unchecked(r) })
+            }
+        }
+
+        impl NarrowsTo<$to> for $from {}
+    )+};
+}
+
+nz_from!(NzI8 => NzI16, NzI32, NzInt, NzI128);
+nz_from!(NzI16 => NzI32, NzInt, NzI128);
+nz_from!(NzI32 => NzInt, NzI128);
+nz_from!(NzInt => NzI128);
+nz_from!(NzU8 => NzU16, NzU32, NzU64, NzU128, NzI16, NzI32, NzInt, NzI128);
+nz_from!(NzU16 => NzU32, NzU64, NzU128, NzI32, NzInt, NzI128);
+nz_from!(NzU32 => NzU64, NzU128, NzInt, NzI128);
+nz_from!(NzU64 => NzU128, NzI128);
+
+nz_try_from!(NzI8 => NzU8, NzU16, NzU32, NzU64, NzU128);
+nz_try_from!(NzI16 => NzI8, NzU8, NzU16, NzU32, NzU64, NzU128);
+nz_try_from!(NzI32 => NzI8, NzI16, NzU8, NzU16, NzU32, NzU64, NzU128);
+nz_try_from!(NzInt => NzI8, NzI16, NzI32, NzU8, NzU16, NzU32, NzU64, NzU128);
+nz_try_from!(NzI128 => NzI8, NzI16, NzI32, NzInt, NzU8, NzU16, NzU32, NzU64, NzU128);
+nz_try_from!(NzU8 => NzI8);
+nz_try_from!(NzU16 => NzU8, NzI8, NzI16);
+nz_try_from!(NzU32 => NzU8, NzU16, NzI8, NzI16, NzI32);
+nz_try_from!(NzU64 => NzU8, NzU16, NzU32, NzI8, NzI16, NzI32, NzInt);
+nz_try_from!(NzU128 => NzU8, NzU16, NzU32, NzU64, NzI8, NzI16, NzI32, NzInt, NzI128);
+
+// Pointer-sized widths: only the conversions core guarantees on every target are lossless.
+nz_from!(NzI8 => NzIsize);
+nz_from!(NzI16 => NzIsize);
+nz_from!(NzU8 => NzIsize, NzUsize);
+nz_from!(NzU16 => NzUsize);
+
+nz_try_from!(NzI8 => NzUsize);
+nz_try_from!(NzI16 => NzUsize);
+nz_try_from!(NzU16 => NzIsize);
+nz_try_from!(NzI32 => NzIsize, NzUsize);
+nz_try_from!(NzInt => NzIsize, NzUsize);
+nz_try_from!(NzI128 => NzIsize, NzUsize);
+nz_try_from!(NzU32 => NzIsize, NzUsize);
+nz_try_from!(NzU64 => NzIsize, NzUsize);
+nz_try_from!(NzU128 => NzIsize, NzUsize);
+nz_try_from!(NzIsize => NzI8, NzI16, NzI32, NzInt, NzI128, NzU8, NzU16, NzU32, NzU64, NzU128, NzUsize);
+nz_try_from!(NzUsize => NzI8, NzI16, NzI32, NzInt, NzI128, NzU8, NzU16, NzU32, NzU64, NzU128, NzIsize);
+
+/* ----- NzInt <-> core::num::NonZero* ----- */
+
+/// Lossless conversion between NzInt and a core NonZero type.
+macro_rules! nz_std_from {
+    ($($from:ty => $to:ty),+ $(,)?) => {$(
+        impl From<$from> for $to {
+            #[inline]
+            fn from(v: $from) -> Self {
+                unsafe { <$to>::new_unchecked(v.get().into()) }
+            }
+        }
+    )+};
+}
+
+/// Lossy conversion between NzInt and a core NonZero type (Err(OutOfRange)).
+macro_rules! nz_std_try_from {
+    ($($from:ty => $to:ty),+ $(,)?) => {$(
+        impl TryFrom<$from> for $to {
+            type Error = NzError;
+            #[inline]
+            fn try_from(v: $from) -> Result<Self, Self::Error> {
+                let r = v.get().try_into().map_err(|_| NzError::OutOfRange)?;
+                Ok(unsafe { <$to>::new_unchecked(r) })
+            }
+        }
+    )+};
+}
+
+nz_std_from!(
+    NonZeroI8 => NzInt, NonZeroI16 => NzInt, NonZeroI32 => NzInt,
+    NonZeroU8 => NzInt, NonZeroU16 => NzInt, NonZeroU32 => NzInt,
+    NzInt => NonZeroI128,
+);
+nz_std_try_from!(
+    NonZeroI128 => NzInt, NonZeroU64 => NzInt, NonZeroU128 => NzInt, NonZeroIsize => NzInt, NonZeroUsize => NzInt,
+    NzInt => NonZeroI8, NzInt => NonZeroI16, NzInt => NonZeroI32, NzInt => NonZeroU8, NzInt => NonZeroU16,
+    NzInt => NonZeroU32, NzInt => NonZeroU64, NzInt => NonZeroU128, NzInt => NonZeroIsize, NzInt => NonZeroUsize,
+);
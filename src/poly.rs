@@ -0,0 +1,66 @@
+//! poly: Checked polynomial evaluation
+//! API:
+//! - eval(coeffs, x) -> Result<NzFloat, PolyError>: coeffs[0] + coeffs[1]*x + coeffs[2]*x^2 + ...
+//! - eval_derivative(coeffs, x): the same for p'(x)
+//!
+//! Design choices:
+//! - Coefficients are in ascending powers, so coeffs[i] is the x^i coefficient and
+//!   PolyError::index names that term directly
+//! - Horner's method; partial sums may pass through zero (p(x) = x^2 - x + 1 at x = 1), so only
+//!   the final value has to be non-zero (and normal, with `deny-subnormals`). NaN (and ±inf
+//!   with `strict-floats`) fails at the term where it first appears
+//! - A polynomial with no terms, or the derivative of a constant, is zero: Err(ZeroResult) at index 0
+
+use crate::nzfloat::{NzFloat, NzfError};
+use crate::nziter::NzStepError;
+
+/// Index of the term where evaluation failed, and why.
+pub type PolyError = NzStepError<NzfError>;
+
+/// Evaluate the polynomial with coefficients `coeffs` (ascending powers) at `x`.
+#[inline]
+pub fn eval(coeffs: &[NzFloat], x: NzFloat) -> Result<NzFloat, PolyError> {
+    horner(
+        coeffs.iter().enumerate().map(|(i, c)| (i, c.get())),
+        x.get(),
+    )
+}
+
+/// Evaluate the derivative p'(x) = coeffs[1] + 2*coeffs[2]*x + 3*coeffs[3]*x^2 + ...
+#[inline]
+pub fn eval_derivative(coeffs: &[NzFloat], x: NzFloat) -> Result<NzFloat, PolyError> {
+    // Term i of p' comes from coeffs[i], so report the original index.
+    let terms = coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| (i, i as f64 * c.get()));
+    horner(terms, x.get())
+}
+
+/// Horner over (index, coefficient) pairs in ascending order.
+fn horner<I: DoubleEndedIterator<Item = (usize, f64)>>(
+    terms: I,
+    x: f64,
+) -> Result<NzFloat, PolyError> {
+    let mut terms = terms.rev();
+    // Seed with the leading term: 0.0 * x would be NaN for x = ±inf.
+    let Some((mut index, mut acc)) = terms.next() else {
+        return Err(PolyError {
+            index: 0,
+            error: NzfError::ZeroResult,
+        });
+    };
+    loop {
+        // Zero and subnormal partial sums are harmless; NaN and (under `strict-floats`) ±inf
+        // cannot recover, so they fail at the term that produced them.
+        if let Some(error) = NzfError::classify(acc.is_nan(), false, acc.is_infinite(), false) {
+            return Err(PolyError { index, error });
+        }
+        match terms.next() {
+            Some((i, c)) => (index, acc) = (i, acc * x + c),
+            None => break,
+        }
+    }
+    NzFloat::check(acc).map_err(|error| PolyError { index, error })
+}
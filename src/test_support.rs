@@ -0,0 +1,56 @@
+//! test_support: Serialization round trips for downstream tests (features `serde` + `json`)
+//! API:
+//! - roundtrip_all_formats(&value): encode and decode `value` through every enabled format
+//!   (serde_json; toml with feature `toml`) and assert it comes back equal
+//!
+//! Design choices:
+//! - Deserialize re-checks the invariants (no 0, no NaN, a sign is ±1), so "comes back equal"
+//!   means "comes back valid and unchanged"; works for any type with Nz fields
+//! - Failures panic with the format's name, the value, and the decoder's message
+//! - A TOML document is a table, so the value travels as the `value` key of a one-entry table
+//! - JSON has no infinities: an infinite NzFloat fails the JSON round trip, as it would outside
+//!   tests
+
+use core::fmt::{Debug, Display};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Round-trip `value` through every enabled format, panicking on the first one that fails.
+#[track_caller]
+pub fn roundtrip_all_formats<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    check("serde_json", value, json(value));
+    #[cfg(feature = "toml")]
+    check("toml", value, toml(value));
+}
+
+#[track_caller]
+fn check<T: PartialEq + Debug, E: Display>(format: &str, value: &T, back: Result<T, E>) {
+    match back {
+        Ok(back) => assert_eq!(&back, value, "{} round trip changed the value", format),
+        Err(e) => panic!("{} round trip of {:?} failed: {}", format, value, e),
+    }
+}
+
+fn json<T: Serialize + DeserializeOwned>(value: &T) -> Result<T, serde_json::Error> {
+    serde_json::from_str(&serde_json::to_string(value)?)
+}
+
+#[cfg(feature = "toml")]
+fn toml<T: Serialize + DeserializeOwned>(value: &T) -> Result<T, alloc::string::String> {
+    use alloc::string::ToString;
+    use toml::{Table, Value};
+
+    let mut doc = Table::new();
+    doc.insert(
+        "value".into(),
+        Value::try_from(value).map_err(|e| e.to_string())?,
+    );
+    let text = toml::to_string(&doc).map_err(|e| e.to_string())?;
+    let mut doc: Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+    let value = doc.remove("value").ok_or("missing `value` key")?;
+    value.try_into().map_err(|e| e.to_string())
+}
@@ -0,0 +1,274 @@
+//! vm: Stack machine for zero-less arithmetic
+//! Invariants:
+//! - Every value on the stack is an NzValue, so no instruction can ever push a zero
+//!
+//! API:
+//! - NzValue: Int(NzInt) | Float(NzFloat) | Sign(nzSign)
+//! - Instr: PushInt/PushFloat/PushSign, Add/Sub/Mul/Div, And/Or/Not, Sign, Jump, JumpIfPos
+//! - run(program) / run_limited(program, max_steps) -> Result<NzValue, VmError>
+//! - run_traced(program, max_steps) -> (Vec<TraceStep>, Result): also the value each step pushed
+//! - VmError = NzStepError<VmFault>: the index of the failing instruction and why
+//!
+//! Design choices:
+//! - Arithmetic is NzInt/NzFloat checked_*; an Int meets a Float by widening the Int (to_float)
+//! - nzSign And/Or are eager (min/max); short-circuiting is spelled with JumpIfPos, which pops
+//!   its condition, so `a && b` is [a, JumpIfPos(skip), PushSign(Neg), Jump(end), b]
+//! - A program ends by running off its end (or jumping to program.len()); the result is the
+//!   top of the stack, and anything below it is discarded
+//! - run() has no step limit; use run_limited for untrusted programs, which may loop forever
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::error::Error;
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+use crate::nziter::NzStepError;
+use crate::nzsign::nzSign;
+
+/// A value the VM operates on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NzValue {
+    Int(NzInt),
+    Float(NzFloat),
+    Sign(nzSign),
+}
+
+/// One VM instruction. Binary operations pop the right operand first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instr {
+    PushInt(NzInt),
+    PushFloat(NzFloat),
+    PushSign(nzSign),
+    Add,
+    Sub,
+    Mul,
+    /// Truncating for two Ints, IEEE otherwise.
+    Div,
+    /// Sign AND (Neg if either is Neg).
+    And,
+    /// Sign OR (Pos if either is Pos).
+    Or,
+    /// Sign NOT.
+    Not,
+    /// Replace the number on top with its sign.
+    Sign,
+    /// Continue at the given instruction index.
+    Jump(usize),
+    /// Pop a sign; continue at the given index if it is Pos, at the next instruction otherwise.
+    JumpIfPos(usize),
+}
+
+/// Why an instruction failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmFault {
+    /// The arithmetic result was zero, NaN, or otherwise invalid.
+    Arith(Error),
+    /// Not enough operands on the stack (or an empty stack at the end).
+    StackUnderflow,
+    /// An operand had the wrong type (e.g., Add on a Sign, And on an Int).
+    TypeMismatch,
+    /// A jump target past the end of the program.
+    BadJump,
+    /// run_limited ran out of steps.
+    StepLimit,
+}
+
+impl fmt::Display for VmFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmFault::Arith(e) => e.fmt(f),
+            VmFault::StackUnderflow => f.write_str("stack underflow"),
+            VmFault::TypeMismatch => f.write_str("operand type mismatch"),
+            VmFault::BadJump => f.write_str("jump target out of range"),
+            VmFault::StepLimit => f.write_str("step limit exceeded"),
+        }
+    }
+}
+
+/// Index of the failing instruction and the fault.
+pub type VmError = NzStepError<VmFault>;
+
+/// Run `program` to completion and return the value on top of the stack.
+#[inline]
+pub fn run(program: &[Instr]) -> Result<NzValue, VmError> {
+    run_limited(program, usize::MAX)
+}
+
+/// Like run, but fail with StepLimit once `max_steps` instructions have executed.
+#[inline]
+pub fn run_limited(program: &[Instr], max_steps: usize) -> Result<NzValue, VmError> {
+    exec(program, max_steps, |_| {})
+}
+
+/// One executed instruction: its index and the value it pushed, if any (None for jumps).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceStep {
+    pub index: usize,
+    pub pushed: Option<NzValue>,
+}
+
+/// Like run_limited, and also return every instruction that completed, in execution order.
+/// On a fault the trace stops before the failing instruction, whose index is in the error.
+pub fn run_traced(
+    program: &[Instr],
+    max_steps: usize,
+) -> (Vec<TraceStep>, Result<NzValue, VmError>) {
+    let mut trace = Vec::new();
+    let result = exec(program, max_steps, |step| trace.push(step));
+    (trace, result)
+}
+
+/// The interpreter loop; `on_step` sees each instruction after it completes.
+#[inline]
+fn exec(
+    program: &[Instr],
+    max_steps: usize,
+    mut on_step: impl FnMut(TraceStep),
+) -> Result<NzValue, VmError> {
+    let mut stack: Vec<NzValue> = Vec::new();
+    let mut pc = 0;
+    let mut steps = 0;
+    while pc < program.len() {
+        let fault = |kind| VmError {
+            index: pc,
+            error: kind,
+        };
+        if steps == max_steps {
+            return Err(fault(VmFault::StepLimit));
+        }
+        steps += 1;
+        let mut next = pc + 1;
+        match program[pc] {
+            Instr::PushInt(x) => stack.push(NzValue::Int(x)),
+            Instr::PushFloat(x) => stack.push(NzValue::Float(x)),
+            Instr::PushSign(s) => stack.push(NzValue::Sign(s)),
+            op @ (Instr::Add | Instr::Sub | Instr::Mul | Instr::Div) => {
+                let (a, b) = pop2(&mut stack).map_err(fault)?;
+                stack.push(arith(op, a, b).map_err(fault)?);
+            }
+            op @ (Instr::And | Instr::Or) => {
+                let (a, b) = pop2(&mut stack).map_err(fault)?;
+                let (a, b) = (sign_of(a).map_err(fault)?, sign_of(b).map_err(fault)?);
+                stack.push(NzValue::Sign(if op == Instr::And {
+                    a.and(b)
+                } else {
+                    a.or(b)
+                }));
+            }
+            Instr::Not => {
+                let a = sign_of(pop(&mut stack).map_err(fault)?).map_err(fault)?;
+                stack.push(NzValue::Sign(a.not()));
+            }
+            Instr::Sign => {
+                let s = match pop(&mut stack).map_err(fault)? {
+                    NzValue::Int(x) => x.sign(),
+                    NzValue::Float(x) => x.sign(),
+                    NzValue::Sign(_) => return Err(fault(VmFault::TypeMismatch)),
+                };
+                stack.push(NzValue::Sign(s));
+            }
+            Instr::Jump(t) => next = t,
+            Instr::JumpIfPos(t) => {
+                if sign_of(pop(&mut stack).map_err(fault)?)
+                    .map_err(fault)?
+                    .is_true()
+                {
+                    next = t;
+                }
+            }
+        }
+        if next > program.len() {
+            return Err(fault(VmFault::BadJump));
+        }
+        let pushed = match program[pc] {
+            Instr::Jump(_) | Instr::JumpIfPos(_) => None,
+            _ => stack.last().copied(),
+        };
+        on_step(TraceStep { index: pc, pushed });
+        pc = next;
+    }
+    stack.pop().ok_or(VmError {
+        index: program.len(),
+        error: VmFault::StackUnderflow,
+    })
+}
+
+#[inline]
+fn pop(stack: &mut Vec<NzValue>) -> Result<NzValue, VmFault> {
+    stack.pop().ok_or(VmFault::StackUnderflow)
+}
+
+/// Pop (left, right); the right operand is on top.
+#[inline]
+fn pop2(stack: &mut Vec<NzValue>) -> Result<(NzValue, NzValue), VmFault> {
+    let b = pop(stack)?;
+    let a = pop(stack)?;
+    Ok((a, b))
+}
+
+#[inline]
+fn sign_of(v: NzValue) -> Result<nzSign, VmFault> {
+    match v {
+        NzValue::Sign(s) => Ok(s),
+        _ => Err(VmFault::TypeMismatch),
+    }
+}
+
+fn arith(op: Instr, a: NzValue, b: NzValue) -> Result<NzValue, VmFault> {
+    let float = |v| match v {
+        NzValue::Int(x) => Ok(NzInt::to_float(x)),
+        NzValue::Float(x) => Ok(x),
+        NzValue::Sign(_) => Err(VmFault::TypeMismatch),
+    };
+    if let (NzValue::Int(a), NzValue::Int(b)) = (a, b) {
+        let r = match op {
+            Instr::Add => a.checked_add(b),
+            Instr::Sub => a.checked_sub(b),
+            Instr::Mul => a.checked_mul(b),
+            _ => a.checked_div(b),
+        };
+        return r.map(NzValue::Int).map_err(|e| VmFault::Arith(e.into()));
+    }
+    let (a, b) = (float(a)?, float(b)?);
+    let r = match op {
+        Instr::Add => a.checked_add(b),
+        Instr::Sub => a.checked_sub(b),
+        Instr::Mul => a.checked_mul(b),
+        _ => a.checked_div(b),
+    };
+    r.map(NzValue::Float).map_err(|e| VmFault::Arith(e.into()))
+}
+
+/* ----- Trait impls ----- */
+
+impl fmt::Display for NzValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NzValue::Int(x) => x.fmt(f),
+            NzValue::Float(x) => x.fmt(f),
+            NzValue::Sign(s) => fmt::Debug::fmt(s, f),
+        }
+    }
+}
+
+impl From<NzInt> for NzValue {
+    #[inline]
+    fn from(x: NzInt) -> Self {
+        NzValue::Int(x)
+    }
+}
+
+impl From<NzFloat> for NzValue {
+    #[inline]
+    fn from(x: NzFloat) -> Self {
+        NzValue::Float(x)
+    }
+}
+
+impl From<nzSign> for NzValue {
+    #[inline]
+    fn from(s: nzSign) -> Self {
+        NzValue::Sign(s)
+    }
+}
@@ -0,0 +1,128 @@
+//! wasm: JavaScript bindings through wasm-bindgen (`wasm` feature)
+//! API:
+//! - JsNzInt (JS class `NzInt`): new NzInt(v: bigint), .value, checkedAdd/Sub/Mul/Div/Rem, checkedNeg
+//! - JsNzFloat (JS class `NzFloat`): new NzFloat(v: number), .value, checkedAdd/Sub/Mul/Div
+//! - toString() on both
+//!
+//! Design choices:
+//! - Failures throw a JS Error whose message is the Rust error's Display
+//!   ("result would be zero", ...), so JS code uses try/catch instead of status checks
+//! - NzInt crosses the boundary as bigint: i64 does not fit a JS number exactly
+
+use std::string::{String, ToString};
+
+use wasm_bindgen::prelude::*;
+
+use crate::nzfloat::NzFloat;
+use crate::nzint::NzInt;
+
+/// NzInt exported to JavaScript.
+#[wasm_bindgen(js_name = NzInt)]
+#[derive(Debug, Clone, Copy)]
+pub struct JsNzInt(NzInt);
+
+#[wasm_bindgen(js_class = NzInt)]
+impl JsNzInt {
+    /// Throws if v is 0n.
+    #[wasm_bindgen(constructor)]
+    pub fn new(v: i64) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(NzInt::try_from(v)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> i64 {
+        self.0.get()
+    }
+
+    #[wasm_bindgen(js_name = checkedAdd)]
+    pub fn checked_add(&self, rhs: &JsNzInt) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_add(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedSub)]
+    pub fn checked_sub(&self, rhs: &JsNzInt) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_sub(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedMul)]
+    pub fn checked_mul(&self, rhs: &JsNzInt) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_mul(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedDiv)]
+    pub fn checked_div(&self, rhs: &JsNzInt) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_div(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedRem)]
+    pub fn checked_rem(&self, rhs: &JsNzInt) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_rem(rhs.0)?))
+    }
+
+    /// Throws only for -2^63.
+    #[wasm_bindgen(js_name = checkedNeg)]
+    pub fn checked_neg(&self) -> Result<JsNzInt, JsError> {
+        Ok(JsNzInt(self.0.checked_neg()?))
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// NzFloat exported to JavaScript.
+#[wasm_bindgen(js_name = NzFloat)]
+#[derive(Debug, Clone, Copy)]
+pub struct JsNzFloat(NzFloat);
+
+#[wasm_bindgen(js_class = NzFloat)]
+impl JsNzFloat {
+    /// Throws if v is ±0 or NaN.
+    #[wasm_bindgen(constructor)]
+    pub fn new(v: f64) -> Result<JsNzFloat, JsError> {
+        Ok(JsNzFloat(NzFloat::try_from(v)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.0.get()
+    }
+
+    #[wasm_bindgen(js_name = checkedAdd)]
+    pub fn checked_add(&self, rhs: &JsNzFloat) -> Result<JsNzFloat, JsError> {
+        Ok(JsNzFloat(self.0.checked_add(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedSub)]
+    pub fn checked_sub(&self, rhs: &JsNzFloat) -> Result<JsNzFloat, JsError> {
+        Ok(JsNzFloat(self.0.checked_sub(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedMul)]
+    pub fn checked_mul(&self, rhs: &JsNzFloat) -> Result<JsNzFloat, JsError> {
+        Ok(JsNzFloat(self.0.checked_mul(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = checkedDiv)]
+    pub fn checked_div(&self, rhs: &JsNzFloat) -> Result<JsNzFloat, JsError> {
+        Ok(JsNzFloat(self.0.checked_div(rhs.0)?))
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<JsNzInt> for NzInt {
+    fn from(x: JsNzInt) -> NzInt {
+        x.0
+    }
+}
+
+impl From<JsNzFloat> for NzFloat {
+    fn from(x: JsNzFloat) -> NzFloat {
+        x.0
+    }
+}
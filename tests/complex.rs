@@ -0,0 +1,55 @@
+use nz::NzComplex;
+use nz::nzfloat::NzfError;
+
+fn c(re: f64, im: f64) -> NzComplex {
+    NzComplex::new(re, im).unwrap()
+}
+
+#[test]
+fn new_checks_only_the_invariant() {
+    assert!(NzComplex::new(0.0, -0.0).is_none());
+    assert!(NzComplex::new(f64::NAN, 1.0).is_none());
+    assert!(NzComplex::new(1.0, f64::NAN).is_none());
+    // Accepted under every feature set, as NzFloat::new accepts them.
+    for (re, im) in [
+        (f64::INFINITY, 0.0),
+        (1.0, f64::NEG_INFINITY),
+        (5e-324, 0.0),
+        (1.0, 1e-310),
+    ] {
+        let z = c(re, im);
+        assert_eq!((z.re(), z.im()), (re, im));
+    }
+}
+
+#[test]
+fn subnormal_components_of_a_large_value_are_fine() {
+    let z = c(1.0, 1e-310);
+    assert_eq!(z.checked_add(c(1.0, 0.0)), Ok(c(2.0, 1e-310)));
+    assert_eq!(z.checked_mul(c(2.0, 0.0)), Ok(c(2.0, 2e-310)));
+}
+
+#[test]
+fn results_follow_the_feature_policy() {
+    // |z| = 1e-310 is subnormal.
+    let tiny = c(1e-310, 0.0).checked_mul(c(0.5, 0.5));
+    if cfg!(feature = "deny-subnormals") {
+        assert_eq!(tiny, Err(NzfError::Underflow));
+    } else {
+        assert_eq!(tiny, Ok(c(5e-311, 5e-311)));
+    }
+    // Both components are subnormal, but |z| ≈ 1.06 · f64::MIN_POSITIVE is not.
+    let edge = f64::MIN_POSITIVE * 0.75;
+    assert!(c(edge, 0.0).checked_add(c(0.0, edge)).is_ok());
+
+    let big = c(1e300, 0.0).checked_mul(c(1e300, 1.0));
+    if cfg!(feature = "strict-floats") {
+        assert_eq!(big, Err(NzfError::Infinite));
+    } else {
+        assert_eq!(big.map(|z| z.re()), Ok(f64::INFINITY));
+    }
+    assert_eq!(
+        c(1.0, 2.0).checked_sub(c(1.0, 2.0)),
+        Err(NzfError::ZeroResult)
+    );
+}
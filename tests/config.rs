@@ -0,0 +1,214 @@
+#![cfg(any(feature = "json", feature = "toml"))]
+
+use nz::nzconfig::ConfigError;
+use nz::nzfloat::NzFloat;
+use nz::nzint::NzInt;
+use nz::nzparse::NzParseError;
+use nz::nzsign::nzSign;
+use nz::vm::NzValue;
+
+fn int(v: i64) -> NzValue {
+    NzValue::Int(NzInt::new(v).unwrap())
+}
+
+fn float(v: f64) -> NzValue {
+    NzValue::Float(NzFloat::new(v).unwrap())
+}
+
+fn at(path: &str, error: NzParseError) -> ConfigError {
+    ConfigError {
+        path: path.into(),
+        error,
+    }
+}
+
+/// Lowered (path, value) pairs as expr::eval_with takes them.
+fn vars(lowered: &[(String, NzValue)]) -> Vec<(&str, NzValue)> {
+    lowered.iter().map(|(k, v)| (k.as_str(), *v)).collect()
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use nz::expr::eval_with;
+    use nz::nzconfig::lower_json;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn scalars_convert_by_kind() {
+        assert_eq!(NzValue::try_from(json!(3)), Ok(int(3)));
+        assert_eq!(NzValue::try_from(json!(i64::MIN)), Ok(int(i64::MIN)));
+        assert_eq!(NzValue::try_from(json!(-2.5)), Ok(float(-2.5)));
+        assert_eq!(
+            NzValue::try_from(&json!(true)),
+            Ok(NzValue::Sign(nzSign::Pos))
+        );
+        assert_eq!(
+            NzValue::try_from(&json!(false)),
+            Ok(NzValue::Sign(nzSign::Neg))
+        );
+
+        assert_eq!(NzValue::try_from(json!(0)), Err(at("", NzParseError::Zero)));
+        assert_eq!(
+            NzValue::try_from(json!(-0.0)),
+            Err(at("", NzParseError::Zero))
+        );
+        // Integers beyond i64 do not silently become floats.
+        assert_eq!(
+            NzValue::try_from(json!(u64::MAX)),
+            Err(at("", NzParseError::Invalid))
+        );
+        for v in [json!(null), json!("1"), json!([1]), json!({"a": 1})] {
+            assert_eq!(NzValue::try_from(v), Err(at("", NzParseError::Invalid)));
+        }
+    }
+
+    #[test]
+    fn values_convert_back() {
+        for v in [int(-7), float(0.25), NzValue::Sign(nzSign::Neg)] {
+            assert_eq!(NzValue::try_from(Value::from(v)), Ok(v));
+        }
+        assert_eq!(Value::from(int(i64::MAX)), json!(i64::MAX));
+        assert_eq!(Value::from(NzValue::Sign(nzSign::Pos)), json!(true));
+        // JSON has no infinities.
+        assert_eq!(Value::from(float(f64::INFINITY)), Value::Null);
+    }
+
+    #[test]
+    fn lowering_names_leaves_by_path() {
+        let tree = json!({
+            "limits": { "max": 10, "strict": false },
+            "servers": [{ "weight": 0.5 }, { "weight": -1.5 }],
+        });
+        let lowered = lower_json(&tree).unwrap();
+        let want = [
+            ("limits.max", int(10)),
+            ("limits.strict", NzValue::Sign(nzSign::Neg)),
+            ("servers.0.weight", float(0.5)),
+            ("servers.1.weight", float(-1.5)),
+        ];
+        assert_eq!(vars(&lowered), want);
+        assert_eq!(lower_json(&json!(4)).unwrap(), [(String::new(), int(4))]);
+        assert_eq!(lower_json(&json!({})).unwrap(), []);
+
+        // The dotted names are expr variables.
+        let vars = vars(&lowered);
+        assert_eq!(
+            eval_with("limits.max * servers.0.weight", &vars),
+            Ok(float(5.0))
+        );
+        assert_eq!(
+            eval_with("servers.0.weight + servers.1.weight", &vars),
+            Ok(float(-1.0))
+        );
+    }
+
+    #[test]
+    fn lowering_errors_carry_the_path() {
+        let tree = json!({ "servers": [{ "weight": 2 }, { "weight": 0 }] });
+        let err = lower_json(&tree).unwrap_err();
+        assert_eq!(err, at("servers.1.weight", NzParseError::Zero));
+        assert_eq!(err.to_string(), "servers.1.weight: value is zero");
+
+        let err = lower_json(&json!({ "a": [1, [2, "x"]] })).unwrap_err();
+        assert_eq!(err, at("a.1.1", NzParseError::Invalid));
+        assert_eq!(
+            lower_json(&json!(0)).unwrap_err().to_string(),
+            "value is zero"
+        );
+    }
+}
+
+#[cfg(feature = "toml")]
+mod toml {
+    use super::*;
+    use ::toml::{Table, Value};
+    use nz::expr::eval_with;
+    use nz::nzconfig::lower_toml;
+
+    fn parse(src: &str) -> Value {
+        Value::Table(src.parse::<Table>().unwrap())
+    }
+
+    #[test]
+    fn scalars_convert_by_kind() {
+        assert_eq!(NzValue::try_from(Value::Integer(-3)), Ok(int(-3)));
+        assert_eq!(NzValue::try_from(&Value::Float(1e300)), Ok(float(1e300)));
+        assert_eq!(
+            NzValue::try_from(Value::Float(f64::NEG_INFINITY)),
+            Ok(float(f64::NEG_INFINITY))
+        );
+        assert_eq!(
+            NzValue::try_from(Value::Boolean(true)),
+            Ok(NzValue::Sign(nzSign::Pos))
+        );
+
+        assert_eq!(
+            NzValue::try_from(Value::Integer(0)),
+            Err(at("", NzParseError::Zero))
+        );
+        assert_eq!(
+            NzValue::try_from(Value::Float(f64::NAN)),
+            Err(at("", NzParseError::NotANumber))
+        );
+        assert_eq!(
+            NzValue::try_from(Value::String("1".into())),
+            Err(at("", NzParseError::Invalid))
+        );
+    }
+
+    #[test]
+    fn values_convert_back() {
+        for v in [
+            int(i64::MIN),
+            float(f64::INFINITY),
+            NzValue::Sign(nzSign::Neg),
+        ] {
+            assert_eq!(NzValue::try_from(Value::from(v)), Ok(v));
+        }
+        assert_eq!(
+            Value::from(NzValue::Sign(nzSign::Pos)),
+            Value::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn lowering_names_leaves_by_path() {
+        let tree = parse(
+            r#"
+            rate = 1.5
+            [limits]
+            max = 12
+            [[servers]]
+            weight = 3
+            [[servers]]
+            weight = -1
+            "#,
+        );
+        let lowered = lower_toml(&tree).unwrap();
+        let want = [
+            ("limits.max", int(12)),
+            ("rate", float(1.5)),
+            ("servers.0.weight", int(3)),
+            ("servers.1.weight", int(-1)),
+        ];
+        assert_eq!(vars(&lowered), want);
+        let vars = vars(&lowered);
+        assert_eq!(
+            eval_with("limits.max / servers.0.weight * rate", &vars),
+            Ok(float(6.0))
+        );
+    }
+
+    #[test]
+    fn lowering_errors_carry_the_path() {
+        let err = lower_toml(&parse("[limits]\nmax = 4\nmin = nan\n")).unwrap_err();
+        assert_eq!(err, at("limits.min", NzParseError::NotANumber));
+        assert_eq!(err.to_string(), "limits.min: value is NaN");
+
+        let err = lower_toml(&parse("[[servers]]\nweight = 0.0\n")).unwrap_err();
+        assert_eq!(err, at("servers.0.weight", NzParseError::Zero));
+        let err = lower_toml(&parse("started = 1979-05-27\n")).unwrap_err();
+        assert_eq!(err, at("started", NzParseError::Invalid));
+    }
+}
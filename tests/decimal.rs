@@ -0,0 +1,259 @@
+//! NzDecimal: Display/FromStr round trips and the rounding of checked_div / rescale.
+
+use nz::{NzDecimal, NzError, NzInt, NzParseError, Rounding};
+
+fn dec(s: &str) -> NzDecimal {
+    s.parse().unwrap()
+}
+
+fn raw(mantissa: i64, scale: u8) -> NzDecimal {
+    NzDecimal::new(NzInt::new(mantissa).unwrap(), scale).unwrap()
+}
+
+#[test]
+fn display_from_str_round_trip() {
+    for (m, s) in [
+        (1, 0),
+        (-1, 0),
+        (1250, 2),
+        (-5, 3),
+        (123_456_789, 18),
+        (i64::MAX, 0),
+        (i64::MIN, 0),
+        (i64::MAX, 18),
+        (i64::MIN, 18),
+        (i64::MIN, 7),
+    ] {
+        let x = raw(m, s);
+        let text = x.to_string();
+        let back: NzDecimal = text.parse().unwrap();
+        assert_eq!((back.mantissa().get(), back.scale()), (m, s), "{text}");
+    }
+}
+
+#[test]
+fn from_str_handles_i64_min() {
+    assert_eq!(dec("-9223372036854775808").mantissa().get(), i64::MIN);
+    let x = dec("-9.223372036854775808");
+    assert_eq!((x.mantissa().get(), x.scale()), (i64::MIN, 18));
+    assert_eq!(
+        "9223372036854775808".parse::<NzDecimal>(),
+        Err(NzParseError::Invalid)
+    );
+    assert_eq!(
+        "-9223372036854775809".parse::<NzDecimal>(),
+        Err(NzParseError::Invalid)
+    );
+}
+
+#[test]
+fn from_str_keeps_the_written_scale() {
+    assert_eq!(dec("-12.50").to_string(), "-12.50");
+    assert_eq!(dec("+.5").to_string(), "0.5");
+    assert_eq!(dec("7.").to_string(), "7");
+    assert_eq!(dec("1.5"), dec("1.500"));
+}
+
+#[test]
+fn from_str_rejects_bad_input() {
+    for s in [
+        "",
+        ".",
+        "-",
+        "+-1",
+        "1.2.3",
+        "1e3",
+        " 1",
+        "0.1234567890123456789",
+    ] {
+        assert_eq!(s.parse::<NzDecimal>(), Err(NzParseError::Invalid), "{s:?}");
+    }
+    assert_eq!("-0.000".parse::<NzDecimal>(), Err(NzParseError::Zero));
+}
+
+#[test]
+fn nearest_rounds_ties_to_even() {
+    let nearest = |s: &str| dec(s).rescale(0, Rounding::Nearest).map(|x| x.to_string());
+    assert_eq!(nearest("2.5"), Ok("2".into()));
+    assert_eq!(nearest("3.5"), Ok("4".into()));
+    assert_eq!(nearest("-2.5"), Ok("-2".into()));
+    assert_eq!(nearest("-3.5"), Ok("-4".into()));
+    assert_eq!(nearest("2.51"), Ok("3".into()));
+    assert_eq!(nearest("-2.49"), Ok("-2".into()));
+    assert_eq!(nearest("0.5"), Err(NzError::ZeroResult));
+    assert_eq!(nearest("1.5"), Ok("2".into()));
+
+    // The same rule through checked_div: 1 / 8 = 0.125 at two digits is a tie.
+    let q = dec("1")
+        .checked_div(dec("8"), 2, Rounding::Nearest)
+        .unwrap();
+    assert_eq!(q.to_string(), "0.12");
+    let q = dec("3")
+        .checked_div(dec("8"), 2, Rounding::Nearest)
+        .unwrap();
+    assert_eq!(q.to_string(), "0.38");
+    let q = dec("-3")
+        .checked_div(dec("8"), 2, Rounding::Nearest)
+        .unwrap();
+    assert_eq!(q.to_string(), "-0.38");
+}
+
+#[test]
+fn directed_rounding() {
+    let round = |s: &str, mode| dec(s).rescale(1, mode).unwrap().to_string();
+    assert_eq!(round("-1.25", Rounding::Floor), "-1.3");
+    assert_eq!(round("-1.25", Rounding::Ceil), "-1.2");
+    assert_eq!(round("-1.25", Rounding::Trunc), "-1.2");
+    assert_eq!(round("1.25", Rounding::Floor), "1.2");
+    assert_eq!(round("1.25", Rounding::Ceil), "1.3");
+    // Exact values are untouched by every mode.
+    assert_eq!(round("1.20", Rounding::Ceil), "1.2");
+    let q = dec("-1").checked_div(dec("3"), 3, Rounding::Floor).unwrap();
+    assert_eq!(q.to_string(), "-0.334");
+}
+
+#[test]
+fn arithmetic_is_exact_or_fails() {
+    assert_eq!(
+        dec("0.1").checked_add(dec("0.2")).unwrap().to_string(),
+        "0.3"
+    );
+    assert_eq!(
+        dec("1.5").checked_mul(dec("-0.25")).unwrap().to_string(),
+        "-0.375"
+    );
+    assert_eq!(
+        dec("1.5").checked_sub(dec("1.50")),
+        Err(NzError::ZeroResult)
+    );
+    assert_eq!(
+        raw(i64::MAX, 0).checked_add(dec("1")),
+        Err(NzError::Overflow)
+    );
+}
+
+fn float(v: f64) -> nz::NzFloat {
+    nz::NzFloat::new(v).unwrap()
+}
+
+#[test]
+fn from_float_rounds_the_binary_value() {
+    let at =
+        |v: f64, scale, mode| NzDecimal::from_float(float(v), scale, mode).map(|x| x.to_string());
+    assert_eq!(at(0.1, 1, Rounding::Nearest), Ok("0.1".into()));
+    assert_eq!(
+        at(0.1, 17, Rounding::Ceil),
+        Ok("0.10000000000000001".into())
+    );
+    assert_eq!(
+        at(0.1, 17, Rounding::Floor),
+        Ok("0.10000000000000000".into())
+    );
+    assert_eq!(at(-2.675, 2, Rounding::Nearest), Ok("-2.67".into())); // -2.67499999...
+    assert_eq!(
+        at(1e15, 3, Rounding::Trunc),
+        Ok("1000000000000000.000".into())
+    );
+    // Exact ties follow the mode: 0.125 is exactly representable.
+    assert_eq!(at(0.125, 2, Rounding::Nearest), Ok("0.12".into()));
+    assert_eq!(at(0.375, 2, Rounding::Nearest), Ok("0.38".into()));
+    assert_eq!(at(-0.125, 2, Rounding::Floor), Ok("-0.13".into()));
+}
+
+#[test]
+fn from_float_tiny_and_huge_values() {
+    let tiny = f64::from_bits(1);
+    assert_eq!(
+        NzDecimal::from_float(float(tiny), 18, Rounding::Nearest),
+        Err(NzError::ZeroResult)
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(tiny), 18, Rounding::Ceil)
+            .unwrap()
+            .to_string(),
+        "0.000000000000000001"
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(-1e-300), 0, Rounding::Floor)
+            .unwrap()
+            .to_string(),
+        "-1"
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(1e19), 0, Rounding::Nearest),
+        Err(NzError::OutOfRange)
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(1e300), 0, Rounding::Nearest),
+        Err(NzError::OutOfRange)
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(f64::INFINITY), 0, Rounding::Nearest),
+        Err(NzError::OutOfRange)
+    );
+    assert_eq!(
+        NzDecimal::from_float(float(1.0), 19, Rounding::Nearest),
+        Err(NzError::OutOfRange)
+    );
+    // 2^62 fits at scale 0 but not at scale 1.
+    let big = float(4_611_686_018_427_387_904.0);
+    assert_eq!(
+        NzDecimal::from_float(big, 0, Rounding::Trunc)
+            .unwrap()
+            .mantissa()
+            .get(),
+        1 << 62
+    );
+    assert_eq!(
+        NzDecimal::from_float(big, 1, Rounding::Trunc),
+        Err(NzError::OutOfRange)
+    );
+    let min = float(-9_223_372_036_854_775_808.0);
+    assert_eq!(
+        NzDecimal::from_float(min, 0, Rounding::Trunc)
+            .unwrap()
+            .mantissa()
+            .get(),
+        i64::MIN
+    );
+}
+
+#[test]
+fn from_float_exact_reports_inexact() {
+    assert_eq!(
+        NzDecimal::from_float_exact(float(0.1), 18),
+        Err(NzError::Inexact)
+    );
+    assert_eq!(
+        NzDecimal::from_float_exact(float(0.125), 2),
+        Err(NzError::Inexact)
+    );
+    assert_eq!(
+        NzDecimal::from_float_exact(float(0.125), 3)
+            .unwrap()
+            .to_string(),
+        "0.125"
+    );
+    assert_eq!(
+        NzDecimal::from_float_exact(float(-37.5), 1)
+            .unwrap()
+            .to_string(),
+        "-37.5"
+    );
+    assert_eq!(
+        NzDecimal::from_float_exact(float(1e-300), 18),
+        Err(NzError::Inexact)
+    );
+    assert_eq!(
+        NzDecimal::from_float_exact(float(1e19), 0),
+        Err(NzError::OutOfRange)
+    );
+}
+
+#[test]
+fn float_round_trip_through_nearest() {
+    for v in [0.1, -2.5, 1234.5678, 3.0e-7, -9.99] {
+        let d = NzDecimal::from_float(float(v), 15, Rounding::Nearest).unwrap();
+        assert_eq!(d.to_float().get(), v, "{d}");
+    }
+}
@@ -0,0 +1,28 @@
+use core::error::Error as _;
+
+use nz::{Error, NzError, NzInt, NzfError};
+
+/// Every message in the source() chain, as an error reporter would print them.
+fn chain(e: &dyn core::error::Error) -> Vec<String> {
+    let mut out = vec![e.to_string()];
+    let mut next = e.source();
+    while let Some(e) = next {
+        out.push(e.to_string());
+        next = e.source();
+    }
+    out
+}
+
+#[test]
+fn display_carries_the_cause_once() {
+    let e: Error = NzInt::one().checked_sub(NzInt::one()).unwrap_err().into();
+    assert_eq!(chain(&e), ["integer error: result is zero"]);
+    assert!(e.source().is_none());
+
+    let e = Error::from(NzfError::NotANumber);
+    assert_eq!(chain(&e), ["float error: result is NaN"]);
+    assert_eq!(
+        Error::from(NzError::Overflow).to_string(),
+        "integer error: result does not fit in i64"
+    );
+}
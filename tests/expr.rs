@@ -0,0 +1,160 @@
+//! The expr front-end: precedence, unary minus, variables, and error spans.
+
+#![cfg(feature = "alloc")]
+
+use nz::expr::{ExprError, ExprErrorKind, ExprTrace, compile, eval, eval_with, explain};
+use nz::vm::{NzValue, VmFault, run};
+use nz::{Error, NzError, NzFloat, NzInt, nzSign};
+
+fn int(v: i64) -> NzValue {
+    NzValue::Int(NzInt::new(v).unwrap())
+}
+
+fn float(v: f64) -> NzValue {
+    NzValue::Float(NzFloat::new(v).unwrap())
+}
+
+/// Evaluate and return the failing span's text with the kind.
+fn failure(src: &str) -> (&str, ExprErrorKind) {
+    let ExprError { span, kind } = eval(src).unwrap_err();
+    (&src[span], kind)
+}
+
+#[test]
+fn precedence_and_associativity() {
+    assert_eq!(eval("1 + 2 * 3"), Ok(int(7)));
+    assert_eq!(eval("(1 + 2) * 3"), Ok(int(9)));
+    assert_eq!(eval("10 - 4 - 3"), Ok(int(3)));
+    assert_eq!(eval("100 / 10 / 5"), Ok(int(2)));
+    assert_eq!(eval("2 * 3 + 4 * 5"), Ok(int(26)));
+    assert_eq!(eval("3 * (7 - 2) / -5"), Ok(int(-3)));
+}
+
+#[test]
+fn unary_minus() {
+    assert_eq!(eval("-7"), Ok(int(-7)));
+    assert_eq!(eval("- 7"), Ok(int(-7)));
+    assert_eq!(eval("--7"), Ok(int(7)));
+    assert_eq!(eval("-(2 + 3)"), Ok(int(-5)));
+    assert_eq!(eval("+4"), Ok(int(4)));
+    assert_eq!(eval("2 * -3"), Ok(int(-6)));
+    assert_eq!(eval("-2.5"), Ok(float(-2.5)));
+    // Only a sign glued to the digits makes i64::MIN a single literal.
+    assert_eq!(eval("-9223372036854775808"), Ok(int(i64::MIN)));
+    assert_eq!(
+        failure("- 9223372036854775808"),
+        ("- 9223372036854775808", ExprErrorKind::BadLiteral)
+    );
+}
+
+#[test]
+fn literal_types() {
+    assert_eq!(eval("7 / 2"), Ok(int(3)));
+    assert_eq!(eval("7 / 2.0"), Ok(float(3.5)));
+    assert_eq!(eval("1e3 + 1"), Ok(float(1001.0)));
+}
+
+#[test]
+fn variables() {
+    let vars = [
+        ("x", int(6)),
+        ("rate", float(0.5)),
+        ("s", NzValue::Sign(nzSign::Neg)),
+    ];
+    assert_eq!(eval_with("x * rate + 1", &vars), Ok(float(4.0)));
+    assert_eq!(eval_with("s", &vars), Ok(NzValue::Sign(nzSign::Neg)));
+    let err = eval_with("x + y", &vars).unwrap_err();
+    assert_eq!((err.span, err.kind), (4..5, ExprErrorKind::UnknownVariable));
+}
+
+#[test]
+fn compile_spans_every_instruction() {
+    let src = "2 * (3 - 1)";
+    let (code, spans) = compile(src, &[]).unwrap();
+    assert_eq!(code.len(), spans.len());
+    let texts: Vec<&str> = spans.iter().map(|s| &src[s.clone()]).collect();
+    assert_eq!(texts, ["2", "3", "1", "3 - 1", "2 * (3 - 1)"]);
+    assert_eq!(run(&code), Ok(int(4)));
+}
+
+#[test]
+fn zero_results_report_the_sub_expression() {
+    assert_eq!(
+        failure("2 * (3 - 3)"),
+        (
+            "3 - 3",
+            ExprErrorKind::Eval(VmFault::Arith(Error::Int(NzError::ZeroResult)))
+        )
+    );
+    assert_eq!(
+        failure("1 / 2 + 5"),
+        (
+            "1 / 2",
+            ExprErrorKind::Eval(VmFault::Arith(Error::Int(NzError::ZeroResult)))
+        )
+    );
+}
+
+#[test]
+fn malformed_input_has_a_span() {
+    assert_eq!(failure("1 + 0"), ("0", ExprErrorKind::ZeroLiteral));
+    assert_eq!(failure("0.0 * 2"), ("0.0", ExprErrorKind::ZeroLiteral));
+    assert_eq!(failure("1 +"), ("", ExprErrorKind::UnexpectedEnd));
+    assert_eq!(eval("1 +").unwrap_err().span, 3..3);
+    assert_eq!(failure("(1 + 2"), ("", ExprErrorKind::UnexpectedEnd));
+    assert_eq!(failure("(1 + 2]"), ("]", ExprErrorKind::Syntax));
+    assert_eq!(failure("1 2"), ("2", ExprErrorKind::Syntax));
+    assert_eq!(failure("3 $ 4"), ("$", ExprErrorKind::Syntax));
+    assert_eq!(failure("1..2 + 3"), ("1..2", ExprErrorKind::BadLiteral));
+    assert_eq!(
+        failure("99999999999999999999"),
+        ("99999999999999999999", ExprErrorKind::BadLiteral)
+    );
+    let err = eval("2 * (1 $)").unwrap_err();
+    assert_eq!(err.to_string(), "syntax error at 7..8");
+}
+
+/// (text, value) of a trace node and, recursively, its operands.
+fn shape(src: &str, t: &ExprTrace) -> String {
+    let inner: Vec<String> = t.children.iter().map(|c| shape(src, c)).collect();
+    if inner.is_empty() {
+        format!("{}={}", &src[t.span.clone()], t.value)
+    } else {
+        format!("{}={}[{}]", &src[t.span.clone()], t.value, inner.join(", "))
+    }
+}
+
+#[test]
+fn explain_builds_the_expression_tree() {
+    let src = "2 * (3 - 1) + x";
+    let (roots, result) = explain(src, &[("x", int(-5))]).unwrap();
+    assert_eq!(result, Ok(int(-1)));
+    assert_eq!(roots.len(), 1);
+    assert_eq!(
+        shape(src, &roots[0]),
+        "2 * (3 - 1) + x=-1[2 * (3 - 1)=4[2=2, 3 - 1=2[3=3, 1=1]], x=-5]"
+    );
+
+    let src = "-(1 + 2)";
+    let (roots, _) = explain(src, &[]).unwrap();
+    assert_eq!(
+        shape(src, &roots[0]),
+        "-(1 + 2)=-3[1 + 2=3[1=1, 2=2], -=-1]"
+    );
+}
+
+#[test]
+fn explain_shows_where_a_zero_appeared() {
+    let src = "2 * (3 - 3)";
+    let (roots, result) = explain(src, &[]).unwrap();
+    let err = result.unwrap_err();
+    assert_eq!(&src[err.span], "3 - 3");
+    // The computed operands, left to right; the last two are those of the failing subtraction.
+    let texts: Vec<String> = roots.iter().map(|t| shape(src, t)).collect();
+    assert_eq!(texts, ["2=2", "3=3", "3=3"]);
+    // Syntax errors fail before anything runs.
+    assert_eq!(
+        explain("1 +", &[]).unwrap_err().kind,
+        ExprErrorKind::UnexpectedEnd
+    );
+}
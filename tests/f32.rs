@@ -0,0 +1,21 @@
+use nz::nzf32::NzF32;
+use nz::nzfloat::NzfError;
+
+#[test]
+fn try_from_classifies_rejected_input() {
+    assert_eq!(NzF32::try_from(f32::NAN), Err(NzfError::NotANumber));
+    assert_eq!(NzF32::try_from(-f32::NAN), Err(NzfError::NotANumber));
+    assert_eq!(NzF32::try_from(0.0f32), Err(NzfError::ZeroResult));
+    assert_eq!(NzF32::try_from(-0.0f32), Err(NzfError::ZeroResult));
+    // Everything NzF32::new accepts, infinities and subnormals included.
+    for v in [1.5f32, -2.0, f32::INFINITY, f32::NEG_INFINITY, 1e-40] {
+        assert_eq!(NzF32::try_from(v).map(NzF32::get), Ok(v));
+    }
+}
+
+#[test]
+fn try_from_agrees_with_new_finite() {
+    for v in [f32::NAN, 0.0, -0.0, 3.0, -1e-40] {
+        assert_eq!(NzF32::try_from(v), NzF32::new_finite(v));
+    }
+}
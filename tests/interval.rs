@@ -0,0 +1,107 @@
+//! NzInterval: outward rounding, division by intervals that straddle zero, infinite bounds.
+
+use nz::{NzFloat, NzInterval, NzIntervalError};
+
+fn f(v: f64) -> NzFloat {
+    NzFloat::new(v).unwrap()
+}
+
+fn iv(lo: f64, hi: f64) -> NzInterval {
+    NzInterval::new(f(lo), f(hi)).unwrap()
+}
+
+fn bounds(x: NzInterval) -> (f64, f64) {
+    (x.lo().get(), x.hi().get())
+}
+
+#[test]
+fn results_are_widened_one_ulp_each_way() {
+    let sum = NzInterval::point(f(0.1))
+        .checked_add(NzInterval::point(f(0.2)))
+        .unwrap();
+    let nearest: f64 = 0.1 + 0.2;
+    assert_eq!(bounds(sum), (nearest.next_down(), nearest.next_up()));
+    // The exact sum 0.3000000000000000166... lies strictly inside.
+    assert!(sum.contains(0.3) && sum.contains(nearest));
+
+    let product = iv(1.0, 3.0).checked_mul(iv(-2.0, 0.5)).unwrap();
+    assert_eq!(bounds(product), ((-6.0f64).next_down(), 1.5f64.next_up()));
+}
+
+#[test]
+fn an_endpoint_rounding_to_zero_steps_past_it() {
+    let diff = NzInterval::point(f(1.0))
+        .checked_sub(NzInterval::point(f(1.0)))
+        .unwrap();
+    let tiny = f64::from_bits(1);
+    assert_eq!(bounds(diff), (-tiny, tiny));
+    assert!(diff.contains_zero());
+}
+
+#[test]
+fn checked_div_refuses_a_straddling_divisor() {
+    assert_eq!(
+        iv(1.0, 2.0).checked_div(iv(-1.0, 4.0)),
+        Err(NzIntervalError::DivisorContainsZero)
+    );
+    let q = iv(1.0, 2.0).checked_div(iv(4.0, 8.0)).unwrap();
+    assert_eq!(bounds(q), (0.125f64.next_down(), 0.5f64.next_up()));
+}
+
+#[test]
+fn div_split_positive_dividend_by_straddling_divisor() {
+    let (neg, pos) = iv(1.0, 2.0).div_split(iv(-1.0, 4.0)).unwrap();
+    let pos = pos.unwrap();
+    assert_eq!(bounds(neg), (f64::NEG_INFINITY, (-1.0f64).next_up()));
+    assert_eq!(bounds(pos), (0.25f64.next_down(), f64::INFINITY));
+    // Every quotient of sampled members lands in one of the halves.
+    for x in [1.0, 1.5, 2.0] {
+        for y in [-1.0, -0.5, -1e-300, 1e-300, 0.25, 4.0] {
+            let q = x / y;
+            assert!(neg.contains(q) || pos.contains(q), "{x} / {y} = {q}");
+        }
+    }
+}
+
+#[test]
+fn div_split_negative_dividend_by_straddling_divisor() {
+    let (neg, pos) = iv(-2.0, -1.0).div_split(iv(-1.0, 4.0)).unwrap();
+    assert_eq!(bounds(neg), (f64::NEG_INFINITY, (-0.25f64).next_up()));
+    assert_eq!(bounds(pos.unwrap()), (1.0f64.next_down(), f64::INFINITY));
+}
+
+#[test]
+fn div_split_straddling_dividend_covers_everything() {
+    assert_eq!(
+        iv(-1.0, 1.0).div_split(iv(-2.0, 3.0)),
+        Ok((NzInterval::entire(), None))
+    );
+}
+
+#[test]
+fn div_split_zero_free_divisor_matches_checked_div() {
+    let (x, y) = (iv(-3.0, 5.0), iv(2.0, 4.0));
+    assert_eq!(x.div_split(y), Ok((x.checked_div(y).unwrap(), None)));
+}
+
+#[test]
+fn infinite_bounds_are_kept() {
+    let entire = NzInterval::entire();
+    assert_eq!(bounds(entire), (f64::NEG_INFINITY, f64::INFINITY));
+    assert_eq!(entire.checked_add(NzInterval::point(f(1.0))), Ok(entire));
+
+    let ray = iv(1.0, f64::INFINITY).checked_mul(iv(-3.0, -2.0)).unwrap();
+    assert_eq!(bounds(ray), (f64::NEG_INFINITY, (-2.0f64).next_up()));
+
+    // 1 / inf = 0.0 is an endpoint that touches zero, so the enclosure straddles it.
+    let shrink = iv(1.0, 2.0).checked_div(iv(1.0, f64::INFINITY)).unwrap();
+    assert_eq!(bounds(shrink), (-f64::from_bits(1), 2.0f64.next_up()));
+    assert!(shrink.contains_zero());
+}
+
+#[test]
+fn infinite_cancellation_is_not_a_number() {
+    let inf = NzInterval::point(f(f64::INFINITY));
+    assert_eq!(inf.checked_sub(inf), Err(NzIntervalError::NotANumber));
+    assert_eq!(inf.checked_div(inf), Err(NzIntervalError::NotANumber));
+}
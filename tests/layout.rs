@@ -0,0 +1,49 @@
+//! Layout guarantees of NzInt/NzFloat that unsafe code may rely on.
+
+use core::mem::{align_of, size_of, transmute};
+
+use nz::{NzFloat, NzInt};
+
+#[test]
+fn int_has_i64_layout() {
+    assert_eq!(size_of::<NzInt>(), size_of::<i64>());
+    assert_eq!(align_of::<NzInt>(), align_of::<i64>());
+}
+
+#[test]
+fn float_has_f64_layout() {
+    assert_eq!(size_of::<NzFloat>(), size_of::<f64>());
+    assert_eq!(align_of::<NzFloat>(), align_of::<f64>());
+}
+
+#[test]
+fn option_int_uses_the_zero_niche() {
+    assert_eq!(size_of::<Option<NzInt>>(), size_of::<i64>());
+    // None is represented by 0 and Some(x) by x itself.
+    assert_eq!(unsafe { transmute::<Option<NzInt>, i64>(None) }, 0);
+    assert_eq!(
+        unsafe { transmute::<Option<NzInt>, i64>(NzInt::new(-7)) },
+        -7
+    );
+    assert_eq!(unsafe { transmute::<i64, Option<NzInt>>(0) }, None);
+}
+
+#[test]
+fn transparent_round_trips() {
+    let x = NzInt::new(i64::MIN).unwrap();
+    assert_eq!(unsafe { transmute::<NzInt, i64>(x) }, i64::MIN);
+    let y = NzFloat::new(-1.5).unwrap();
+    assert_eq!(
+        unsafe { transmute::<NzFloat, f64>(y) }.to_bits(),
+        (-1.5f64).to_bits()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn slices_view_in_place() {
+    let raw = [3i64, -1, i64::MAX];
+    let view = nz::validate_chunked(&raw, nz::NzUsize::new(2).unwrap(), |_| {}).unwrap();
+    assert_eq!(view.as_ptr().cast::<i64>(), raw.as_ptr());
+    assert_eq!(view.iter().map(|x| x.get()).collect::<Vec<_>>(), raw);
+}
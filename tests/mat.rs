@@ -0,0 +1,95 @@
+//! NzMat2/NzMat3: the stored inverse, singular and non-finite inputs, matrix-vector products.
+
+use nz::{NzMat2, NzMat3, NzVec2, NzVec3, NzfError};
+
+fn close<const N: usize>(a: [[f64; N]; N], b: [[f64; N]; N]) -> bool {
+    a.iter()
+        .flatten()
+        .zip(b.iter().flatten())
+        .all(|(x, y)| (x - y).abs() <= 1e-12 * (1.0 + y.abs()))
+}
+
+#[test]
+fn the_stored_inverse_inverts() {
+    let a = NzMat2::new([[4.0, 7.0], [2.0, 6.0]]).unwrap();
+    let i = a.checked_mul(a.inverse()).unwrap();
+    assert!(close(i.to_rows(), NzMat2::identity().to_rows()), "{i}");
+    assert!(close(a.inverse().to_rows(), [[0.6, -0.7], [-0.2, 0.4]]));
+    assert!((a.det().get() * a.inverse().det().get() - 1.0).abs() < 1e-12);
+
+    let b = NzMat3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]).unwrap();
+    let i = b.inverse().checked_mul(b).unwrap();
+    assert!(close(i.to_rows(), NzMat3::identity().to_rows()), "{i}");
+}
+
+#[test]
+fn inverting_twice_returns_the_original_exactly() {
+    let rows = [[0.1, 0.7, 0.3], [0.2, 0.5, 0.9], [0.8, 0.4, 0.6]];
+    let m = NzMat3::new(rows).unwrap();
+    assert_eq!(m.inverse().inverse().to_rows(), rows);
+    assert_eq!(m.inverse().inverse(), m);
+}
+
+#[test]
+fn a_matrix_whose_inverse_overflows_is_rejected() {
+    // det = 1e-307 is a normal non-zero value, but adj/det has the entry -1e5 / 1e-307 = -inf.
+    assert_eq!(
+        NzMat2::try_from([[1e-305, 1e5], [0.0, 1e-2]]),
+        Err(NzfError::Infinite)
+    );
+}
+
+#[test]
+fn singular_and_non_finite_inputs_are_rejected() {
+    assert_eq!(
+        NzMat2::try_from([[1.0, 2.0], [2.0, 4.0]]),
+        Err(NzfError::ZeroResult)
+    );
+    assert!(NzMat2::new([[0.0, 0.0], [0.0, 0.0]]).is_none());
+    let dependent = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]];
+    assert_eq!(NzMat3::try_from(dependent), Err(NzfError::ZeroResult));
+    assert_eq!(
+        NzMat2::try_from([[f64::NAN, 0.0], [0.0, 1.0]]),
+        Err(NzfError::NotANumber)
+    );
+    assert_eq!(
+        NzMat2::try_from([[f64::INFINITY, 0.0], [0.0, 1.0]]),
+        Err(NzfError::Infinite)
+    );
+    // The determinant underflows to zero even though no row is zero.
+    assert_eq!(
+        NzMat2::try_from([[1e-200, 0.0], [0.0, 1e-200]]),
+        Err(NzfError::ZeroResult)
+    );
+}
+
+#[test]
+fn checked_mul_reports_overflow() {
+    let big = NzMat2::new([[1e200, 0.0], [0.0, 1.0]]).unwrap();
+    assert_eq!(big.checked_mul(big), Err(NzfError::Infinite));
+}
+
+#[test]
+fn checked_mul_vec_maps_non_zero_to_non_zero() {
+    let m = NzMat2::new([[2.0, 1.0], [1.0, 1.0]]).unwrap();
+    let v = m.checked_mul_vec(NzVec2::new(1.0, -1.0).unwrap()).unwrap();
+    assert_eq!((v.x(), v.y()), (1.0, 0.0));
+    // And back through the inverse.
+    let w = m.inverse().checked_mul_vec(v).unwrap();
+    assert!((w.x() - 1.0).abs() < 1e-15 && (w.y() + 1.0).abs() < 1e-15);
+
+    let rot = NzMat3::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]).unwrap();
+    let r = rot
+        .checked_mul_vec(NzVec3::new(1.0, 0.0, 0.0).unwrap())
+        .unwrap();
+    assert_eq!(r.to_array(), [0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn checked_mul_vec_reports_underflow() {
+    let m = NzMat2::new([[1e-150, 0.0], [0.0, 1e150]]).unwrap();
+    assert_eq!(
+        m.checked_mul_vec(NzVec2::new(1e-200, 0.0).unwrap()),
+        Err(NzfError::ZeroResult)
+    );
+}
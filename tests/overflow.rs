@@ -0,0 +1,175 @@
+use nz::nzfloat::{NzFloat, NzfError};
+use nz::nzint::{NzError, NzInt};
+use nz::{OverflowPolicy, Strict};
+
+use OverflowPolicy::{Saturating, Strict as Checked, Wrapping};
+
+fn int(v: i64) -> NzInt {
+    NzInt::new(v).unwrap()
+}
+
+fn float(v: f64) -> NzFloat {
+    NzFloat::new(v).unwrap()
+}
+
+const SAMPLES: [i64; 8] = [1, -1, 2, -3, 1 << 40, i64::MAX, i64::MIN, i64::MIN + 1];
+
+#[test]
+fn wrapping_is_the_plain_checked_method() {
+    for a in SAMPLES.map(int) {
+        for b in SAMPLES.map(int) {
+            assert_eq!(a.add_with(b, Wrapping), a.checked_add(b));
+            assert_eq!(a.sub_with(b, Wrapping), a.checked_sub(b));
+            assert_eq!(a.mul_with(b, Wrapping), a.checked_mul(b));
+            assert_eq!(a.div_with(b, Wrapping), a.checked_div(b));
+        }
+        assert_eq!(a.neg_with(Wrapping), a.checked_neg());
+        assert_eq!(a.abs_with(Wrapping), a.checked_abs());
+        for n in [0, 1, 2, 31, 62, 63, 64, 100] {
+            assert_eq!(a.pow_with(n, Wrapping), a.checked_pow(n));
+            assert_eq!(a.shl_with(n, Wrapping), a.checked_shl(n));
+        }
+    }
+}
+
+#[test]
+fn division_overflow_is_not_wrapped() {
+    // i64::MIN / -1 has no wrapped value in checked_div, so Wrapping reports it too.
+    let (min, neg_one) = (int(i64::MIN), int(-1));
+    assert_eq!(min.div_with(neg_one, Wrapping), Err(NzError::DivOverflow));
+    assert_eq!(min.div_with(neg_one, Checked), Err(NzError::Overflow));
+    assert_eq!(min.div_with(neg_one, Saturating), Ok(int(i64::MAX)));
+    // Whereas addition does wrap.
+    assert_eq!(int(i64::MAX).add_with(int(1), Wrapping), Ok(int(i64::MIN)));
+    assert_eq!(
+        int(i64::MAX).add_with(int(1), Checked),
+        Err(NzError::Overflow)
+    );
+    assert_eq!(
+        int(i64::MAX).add_with(int(1), Saturating),
+        Ok(int(i64::MAX))
+    );
+}
+
+#[test]
+fn unary_and_power_policies() {
+    let min = int(i64::MIN);
+    for op in [NzInt::neg_with, NzInt::abs_with] {
+        assert_eq!(op(min, Wrapping), Err(NzError::DivOverflow));
+        assert_eq!(op(min, Checked), Err(NzError::Overflow));
+        assert_eq!(op(min, Saturating), Ok(int(i64::MAX)));
+    }
+    assert_eq!(int(-5).abs_with(Checked), Ok(int(5)));
+
+    assert_eq!(int(3).pow_with(40, Checked), Err(NzError::Overflow));
+    assert_eq!(int(3).pow_with(40, Saturating), Ok(int(i64::MAX)));
+    assert_eq!(int(-3).pow_with(41, Saturating), Ok(int(i64::MIN)));
+    assert_eq!(int(-2).pow_with(63, Checked), Ok(min));
+
+    // 1 << 63 flips the sign: overflow, saturating toward the sign of self.
+    assert_eq!(int(1).shl_with(63, Checked), Err(NzError::Overflow));
+    assert_eq!(int(1).shl_with(63, Saturating), Ok(int(i64::MAX)));
+    assert_eq!(int(-1).shl_with(63, Checked), Ok(min));
+    assert_eq!(int(-5).shl_with(64, Saturating), Ok(min));
+}
+
+#[test]
+fn zero_results_fail_under_every_policy() {
+    for policy in [Checked, Wrapping, Saturating] {
+        assert_eq!(int(4).add_with(int(-4), policy), Err(NzError::ZeroResult));
+        assert_eq!(int(4).sub_with(int(4), policy), Err(NzError::ZeroResult));
+        assert_eq!(int(3).div_with(int(4), policy), Err(NzError::ZeroResult));
+        assert_eq!(
+            float(1.5).sub_with(float(1.5), policy),
+            Err(NzfError::ZeroResult)
+        );
+    }
+    // 2^32 * 2^32 wraps to exactly zero.
+    assert_eq!(
+        int(1 << 32).mul_with(int(1 << 32), Wrapping),
+        Err(NzError::ZeroResult)
+    );
+}
+
+#[test]
+fn float_policies() {
+    let big = float(1e300);
+    assert_eq!(big.mul_with(big, Checked), Err(NzfError::Infinite));
+    assert_eq!(big.mul_with(big, Saturating), Ok(float(f64::MAX)));
+    assert_eq!(
+        (-big).div_with(float(1e-300), Saturating),
+        Ok(float(f64::MIN))
+    );
+    // Infinite operands are not overflow.
+    let inf = float(f64::INFINITY);
+    assert_eq!(inf.add_with(big, Saturating), big.checked_add(inf));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn float_power_policy() {
+    let ten = float(10.0);
+    assert_eq!(
+        ten.powf_with(float(400.0), Checked),
+        Err(NzfError::Infinite)
+    );
+    assert_eq!(ten.powf_with(float(400.0), Saturating), Ok(float(f64::MAX)));
+    assert_eq!(
+        Strict::new(ten).checked_powf(Strict::new(float(2.0))),
+        Ok(Strict::new(float(100.0)))
+    );
+}
+
+#[test]
+fn strict_checked_methods_report_overflow() {
+    let max = Strict::new(int(i64::MAX));
+    assert_eq!(max.checked_add(Strict::new(int(1))), Err(NzError::Overflow));
+    assert_eq!(
+        Strict::new(int(i64::MIN)).checked_neg(),
+        Err(NzError::Overflow)
+    );
+    assert_eq!(
+        Strict::new(int(i64::MIN)).checked_abs(),
+        Err(NzError::Overflow)
+    );
+    assert_eq!(Strict::new(int(10)).checked_pow(19), Err(NzError::Overflow));
+    assert_eq!(Strict::new(int(3)).checked_shl(62), Err(NzError::Overflow));
+    assert_eq!(Strict::new(int(3)).checked_shl(2), Ok(Strict::new(int(12))));
+}
+
+#[test]
+fn strict_operators() {
+    let mut x = Strict::new(int(6));
+    x *= Strict::new(int(7));
+    x -= Strict::new(int(2));
+    x /= Strict::new(int(-8));
+    assert_eq!(-x, Strict::new(int(5)));
+
+    let mut y = Strict::new(float(1.5));
+    y += Strict::new(float(2.5));
+    assert_eq!((-y / Strict::new(float(8.0))).get(), float(-0.5));
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn strict_add_panics_on_overflow() {
+    let _ = Strict::new(int(i64::MAX)) + Strict::new(int(1));
+}
+
+#[test]
+#[should_panic(expected = "attempt to negate with overflow")]
+fn strict_neg_panics_on_overflow() {
+    let _ = -Strict::new(int(i64::MIN));
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with infinite result")]
+fn strict_float_mul_panics_on_overflow() {
+    let _ = Strict::new(float(1e300)) * Strict::new(float(1e300));
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with zero result")]
+fn strict_sub_panics_on_zero() {
+    let _ = Strict::new(int(3)) - Strict::new(int(3));
+}
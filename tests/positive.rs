@@ -0,0 +1,37 @@
+use nz::nzfloat::NzfError;
+use nz::nzpositive::{NgFloat, PzFloat};
+
+fn pz(v: f64) -> PzFloat {
+    PzFloat::new(v).unwrap()
+}
+
+#[test]
+fn float_checked_ops_follow_the_feature_rules() {
+    let sub = pz(1e-310);
+    let sum = sub.checked_add(sub).map(PzFloat::get);
+    let product = pz(1e-160).checked_mul(pz(1e-160)).map(PzFloat::get);
+    if cfg!(feature = "deny-subnormals") {
+        assert_eq!(sum, Err(NzfError::Underflow));
+        assert_eq!(product, Err(NzfError::Underflow));
+    } else {
+        assert_eq!(sum, Ok(2e-310));
+        assert_eq!(product, Ok(1e-160 * 1e-160));
+    }
+
+    let big = pz(f64::MAX).checked_add(pz(f64::MAX)).map(PzFloat::get);
+    if cfg!(feature = "strict-floats") {
+        assert_eq!(big, Err(NzfError::Infinite));
+    } else {
+        assert_eq!(big, Ok(f64::INFINITY));
+    }
+
+    assert_eq!(
+        pz(1e-200).checked_mul(pz(1e-200)).map(PzFloat::get),
+        Err(NzfError::ZeroResult)
+    );
+    let neg = NgFloat::new(-1e-200).unwrap();
+    assert_eq!(
+        neg.checked_mul(neg).map(PzFloat::get),
+        Err(NzfError::ZeroResult)
+    );
+}
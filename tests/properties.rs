@@ -0,0 +1,125 @@
+//! Property tests for the checked arithmetic (run with `--features proptest,quickcheck`).
+
+#![cfg(all(feature = "proptest", feature = "quickcheck"))]
+
+use nz::{NzError, NzFloat, NzInt, NzfError, nzSign};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestError, TestRunner};
+use quickcheck::{Arbitrary, Gen};
+
+proptest! {
+    #[test]
+    fn int_add_sub_mul_wrap_and_reject_only_zero(a: NzInt, b: NzInt) {
+        let (x, y) = (a.get(), b.get());
+        for (got, want) in [
+            (a.checked_add(b), x.wrapping_add(y)),
+            (a.checked_sub(b), x.wrapping_sub(y)),
+            (a.checked_mul(b), x.wrapping_mul(y)),
+        ] {
+            match got {
+                Ok(r) => prop_assert_eq!(r.get(), want),
+                Err(e) => {
+                    prop_assert_eq!(e, NzError::ZeroResult);
+                    prop_assert_eq!(want, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn int_div_truncates_or_reports(a: NzInt, b: NzInt) {
+        match a.checked_div(b) {
+            Ok(q) => prop_assert_eq!(q.get(), a.get() / b.get()),
+            Err(NzError::DivOverflow) => prop_assert!(a.get() == i64::MIN && b.get() == -1),
+            Err(e) => {
+                prop_assert_eq!(e, NzError::ZeroResult);
+                prop_assert!(a.abs_unsigned() < b.abs_unsigned());
+            }
+        }
+    }
+
+    #[test]
+    fn float_ops_never_produce_zero_or_nan(a: NzFloat, b: NzFloat) {
+        for r in [a.checked_add(b), a.checked_sub(b), a.checked_mul(b), a.checked_div(b)] {
+            match r {
+                Ok(v) => prop_assert!(v.get() != 0.0 && !v.get().is_nan()),
+                Err(e) => prop_assert!(matches!(
+                    e,
+                    NzfError::ZeroResult | NzfError::NotANumber | NzfError::Infinite | NzfError::Underflow
+                )),
+            }
+        }
+    }
+
+    #[test]
+    fn float_add_matches_f64(a: NzFloat, b: NzFloat) {
+        let want = a.get() + b.get();
+        match a.checked_add(b) {
+            Ok(v) => prop_assert_eq!(v.get().to_bits(), want.to_bits()),
+            Err(_) => prop_assert!(!want.is_normal()),
+        }
+    }
+
+    #[test]
+    fn sign_round_trips(s: nzSign, x: NzFloat) {
+        prop_assert_eq!(nzSign::from_i8(s.to_i8()), Some(s));
+        prop_assert_eq!(x.with_sign(s).sign(), s);
+    }
+}
+
+/// Minimal counterexample proptest settles on for `fails`.
+fn proptest_minimum<T: proptest::arbitrary::Arbitrary + Copy>(fails: fn(T) -> bool) -> T {
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+    match runner.run(&any::<T>(), |x| {
+        prop_assert!(!fails(x));
+        Ok(())
+    }) {
+        Err(TestError::Fail(_, x)) => x,
+        other => panic!("expected a failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn proptest_shrinks_toward_one() {
+    let m = proptest_minimum::<NzInt>(|x| x.abs_unsigned().get() >= 1000);
+    assert_eq!(m.abs_unsigned().get(), 1000);
+
+    let m = proptest_minimum::<NzFloat>(|x| x.get().abs() >= 2.0);
+    assert_eq!(m.get().abs(), 2.0);
+
+    // Magnitudes below 1 shrink upward, toward 1.
+    let m = proptest_minimum::<NzFloat>(|x| x.get().abs() <= 0.5);
+    assert_eq!(m.get().abs(), 0.5);
+}
+
+#[test]
+fn quickcheck_shrinks_stay_non_zero_and_start_at_one() {
+    let mut g = Gen::new(1000);
+    for _ in 0..200 {
+        let x = NzInt::arbitrary(&mut g);
+        let shrunk: Vec<NzInt> = x.shrink().collect();
+        if x.abs_unsigned().get() != 1 {
+            assert_eq!(shrunk[0].get(), x.get().signum());
+        }
+        assert!(shrunk.iter().all(|s| s.get().signum() == x.get().signum()));
+
+        let f = NzFloat::arbitrary(&mut g);
+        for s in f.shrink() {
+            assert!(s.get() != 0.0 && !s.get().is_nan());
+            assert_eq!(s.get().is_sign_negative(), f.get().is_sign_negative());
+        }
+    }
+    assert_eq!(nzSign::Neg.shrink().collect::<Vec<_>>(), [nzSign::Pos]);
+    assert_eq!(nzSign::Pos.shrink().count(), 0);
+}
+
+#[test]
+fn quickcheck_int_mul_is_commutative() {
+    fn prop(a: NzInt, b: NzInt) -> bool {
+        a.checked_mul(b) == b.checked_mul(a)
+    }
+    quickcheck::quickcheck(prop as fn(NzInt, NzInt) -> bool);
+}
@@ -0,0 +1,139 @@
+//! Seeded checks of the samplers in nzrandom (run with `--features rand,alloc`).
+
+#![cfg(feature = "rand")]
+
+use nz::{NzFloat, NzInt, NzUniform};
+use rand::RngCore;
+use rand::distr::Distribution;
+
+/// SplitMix64: a fixed, dependency-free generator so every run draws the same sequence.
+struct SplitMix(u64);
+
+impl RngCore for SplitMix {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+fn int(v: i64) -> NzInt {
+    NzInt::new(v).unwrap()
+}
+
+#[test]
+fn int_range_straddling_zero_yields_only_plus_minus_one() {
+    let mut rng = SplitMix(1);
+    let dist = NzUniform::<NzInt>::new(int(-1), int(2)).unwrap();
+    let mut seen = [0u32; 2];
+    for _ in 0..10_000 {
+        match dist.sample(&mut rng).get() {
+            -1 => seen[0] += 1,
+            1 => seen[1] += 1,
+            v => panic!("sampled {v} outside {{-1, 1}}"),
+        }
+    }
+    // Two values, equally likely.
+    assert!(
+        seen.iter().all(|&n| (4_500..5_500).contains(&n)),
+        "{seen:?}"
+    );
+}
+
+#[test]
+fn int_range_covers_every_non_zero_value() {
+    let mut rng = SplitMix(2);
+    let dist = NzUniform::<NzInt>::new(int(-3), int(3)).unwrap();
+    let mut seen = [0u32; 6];
+    for _ in 0..60_000 {
+        let v = dist.sample(&mut rng).get();
+        assert!((-3..3).contains(&v) && v != 0, "sampled {v}");
+        seen[(v + 3) as usize] += 1;
+    }
+    assert_eq!(seen[3], 0);
+    for (i, &n) in seen.iter().enumerate().filter(|&(i, _)| i != 3) {
+        assert!(
+            (11_000..13_000).contains(&n),
+            "value {} drawn {n} times",
+            i as i64 - 3
+        );
+    }
+}
+
+#[test]
+fn empty_int_range_is_rejected() {
+    assert!(NzUniform::<NzInt>::new(int(2), int(2)).is_none());
+    assert!(NzUniform::<NzInt>::new(int(2), int(-2)).is_none());
+    // [-1, 1) minus zero is not empty: it is {-1}.
+    let mut rng = SplitMix(6);
+    let dist = NzUniform::<NzInt>::new(int(-1), int(1)).unwrap();
+    assert!((0..100).all(|_| dist.sample(&mut rng) == int(-1)));
+}
+
+#[test]
+fn float_range_straddling_zero_stays_in_bounds() {
+    let mut rng = SplitMix(3);
+    let (low, high) = (NzFloat::new(-1.0).unwrap(), NzFloat::new(3.0).unwrap());
+    let dist = NzUniform::<NzFloat>::new(low, high).unwrap();
+    let mut negative = 0;
+    for _ in 0..10_000 {
+        let v = dist.sample(&mut rng).get();
+        assert!(v != 0.0 && (-1.0..3.0).contains(&v), "sampled {v}");
+        negative += (v < 0.0) as u32;
+    }
+    // A quarter of the range is negative.
+    assert!((2_200..2_800).contains(&negative), "{negative}");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn weighted_index_matches_its_weights() {
+    use nz::{NzWeightedIndex, PzFloat};
+
+    let mut rng = SplitMix(4);
+    let weights: Vec<PzFloat> = [1.0, 2.0, 3.0, 4.0, 0.5]
+        .iter()
+        .map(|&w| PzFloat::new(w).unwrap())
+        .collect();
+    let total: f64 = weights.iter().map(|w| w.get()).sum();
+    let table = NzWeightedIndex::new(&weights).unwrap();
+    assert_eq!(table.len().get(), weights.len());
+    let draws = 200_000;
+    let mut counts = vec![0u32; weights.len()];
+    for _ in 0..draws {
+        counts[table.sample(&mut rng)] += 1;
+    }
+    for (i, (&n, w)) in counts.iter().zip(&weights).enumerate() {
+        let (got, want) = (n as f64 / draws as f64, w.get() / total);
+        assert!(
+            (got - want).abs() < 0.005,
+            "index {i}: frequency {got}, weight share {want}"
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn weighted_index_gives_infinite_weights_everything() {
+    use nz::{NzWeightedIndex, PzFloat};
+
+    let mut rng = SplitMix(5);
+    let weights = [
+        PzFloat::new(1e300).unwrap(),
+        PzFloat::new(f64::INFINITY).unwrap(),
+    ];
+    let table = NzWeightedIndex::new(&weights).unwrap();
+    assert!((0..1_000).all(|_| table.sample(&mut rng) == 1));
+}
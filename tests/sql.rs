@@ -0,0 +1,37 @@
+#![cfg(feature = "postgres")]
+
+use bytes::BytesMut;
+use nz::nzparse::NzParseError;
+use nz::{NzFloat, NzInt};
+use postgres_types::{FromSql, ToSql, Type};
+
+fn float8(v: f64) -> Result<NzFloat, NzParseError> {
+    NzFloat::from_sql(&Type::FLOAT8, &v.to_be_bytes())
+        .map_err(|e| *e.downcast::<NzParseError>().unwrap())
+}
+
+#[test]
+fn floats_decode_like_nzfloat_new() {
+    // Infinities and subnormals decode under every feature set, as with serde.
+    for v in [1.5, -2.0, f64::INFINITY, f64::NEG_INFINITY, 1e-310, -5e-324] {
+        assert_eq!(float8(v), Ok(NzFloat::new(v).unwrap()));
+    }
+    assert_eq!(float8(0.0), Err(NzParseError::Zero));
+    assert_eq!(float8(-0.0), Err(NzParseError::Zero));
+    assert_eq!(float8(f64::NAN), Err(NzParseError::NotANumber));
+}
+
+#[test]
+fn values_round_trip() {
+    let mut buf = BytesMut::new();
+    let x = NzFloat::new(1e-310).unwrap();
+    x.to_sql(&Type::FLOAT8, &mut buf).unwrap();
+    assert_eq!(NzFloat::from_sql(&Type::FLOAT8, &buf).unwrap(), x);
+
+    buf.clear();
+    let n = NzInt::new(i64::MIN).unwrap();
+    n.to_sql(&Type::INT8, &mut buf).unwrap();
+    assert_eq!(NzInt::from_sql(&Type::INT8, &buf).unwrap(), n);
+    let err = NzInt::from_sql(&Type::INT8, &0i64.to_be_bytes()).unwrap_err();
+    assert_eq!(*err.downcast::<NzParseError>().unwrap(), NzParseError::Zero);
+}
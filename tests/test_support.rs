@@ -0,0 +1,59 @@
+#![cfg(all(feature = "serde", feature = "json"))]
+
+use nz::nzfloat::NzFloat;
+use nz::nzint::NzInt;
+use nz::nzsign::nzSign;
+use nz::test_support::roundtrip_all_formats;
+
+#[test]
+fn ints_survive_every_format() {
+    for v in [1, -1, 2, i64::MAX, i64::MIN, -1234567890123] {
+        roundtrip_all_formats(&NzInt::new(v).unwrap());
+    }
+}
+
+#[test]
+fn floats_survive_every_format() {
+    let edge = [
+        f64::MIN_POSITIVE,
+        5e-324,
+        -5e-324,
+        f64::MAX,
+        f64::MIN,
+        f64::EPSILON,
+    ];
+    for v in [1.0, -1.0, 0.1, -2.5e-300, 1e300].into_iter().chain(edge) {
+        roundtrip_all_formats(&NzFloat::new(v).unwrap());
+    }
+}
+
+#[test]
+fn signs_survive_every_format() {
+    roundtrip_all_formats(&nzSign::Pos);
+    roundtrip_all_formats(&nzSign::Neg);
+}
+
+#[test]
+fn containers_of_nz_values_survive_every_format() {
+    let ints: Vec<NzInt> = [3, -1, i64::MIN]
+        .iter()
+        .map(|&v| NzInt::new(v).unwrap())
+        .collect();
+    roundtrip_all_formats(&ints);
+    roundtrip_all_formats(&vec![nzSign::Neg, nzSign::Pos]);
+}
+
+#[test]
+#[should_panic(expected = "serde_json round trip")]
+fn infinite_floats_do_not_survive_json() {
+    roundtrip_all_formats(&NzFloat::new(f64::INFINITY).unwrap());
+}
+
+#[test]
+fn decoding_rejects_what_the_invariants_forbid() {
+    // What the round trip relies on: a value that decodes at all is valid.
+    assert!(serde_json::from_str::<NzInt>("0").is_err());
+    assert!(serde_json::from_str::<NzFloat>("-0.0").is_err());
+    assert!(serde_json::from_str::<nzSign>("0").is_err());
+    assert!(serde_json::from_str::<nzSign>("2").is_err());
+}
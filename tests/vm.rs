@@ -0,0 +1,212 @@
+//! The vm stack machine: faults, jumps, the step limit and mixed Int/Float arithmetic.
+
+#![cfg(feature = "alloc")]
+
+use nz::vm::{Instr, NzValue, TraceStep, VmError, VmFault, run, run_limited, run_traced};
+use nz::{Error, NzError, NzFloat, NzInt, nzSign};
+
+fn int(v: i64) -> Instr {
+    Instr::PushInt(NzInt::new(v).unwrap())
+}
+
+fn float(v: f64) -> Instr {
+    Instr::PushFloat(NzFloat::new(v).unwrap())
+}
+
+fn fault(index: usize, error: VmFault) -> Result<NzValue, VmError> {
+    Err(VmError { index, error })
+}
+
+#[test]
+fn arithmetic_pops_the_right_operand_first() {
+    assert_eq!(
+        run(&[int(7), int(2), Instr::Sub]),
+        Ok(NzValue::Int(NzInt::new(5).unwrap()))
+    );
+    assert_eq!(
+        run(&[int(7), int(2), Instr::Div]),
+        Ok(NzValue::Int(NzInt::new(3).unwrap()))
+    );
+}
+
+#[test]
+fn zero_results_fault_at_the_instruction() {
+    assert_eq!(
+        run(&[int(3), int(3), Instr::Sub]),
+        fault(2, VmFault::Arith(Error::Int(NzError::ZeroResult)))
+    );
+    assert_eq!(
+        run(&[int(1), int(2), Instr::Div]),
+        fault(2, VmFault::Arith(Error::Int(NzError::ZeroResult)))
+    );
+}
+
+#[test]
+fn stack_underflow() {
+    assert_eq!(
+        run(&[int(1), Instr::Add]),
+        fault(1, VmFault::StackUnderflow)
+    );
+    assert_eq!(run(&[Instr::Not]), fault(0, VmFault::StackUnderflow));
+    // An empty program leaves nothing to return; the fault points past the end.
+    assert_eq!(run(&[]), fault(0, VmFault::StackUnderflow));
+    assert_eq!(
+        run(&[Instr::PushSign(nzSign::Pos), Instr::JumpIfPos(2)]),
+        fault(2, VmFault::StackUnderflow)
+    );
+}
+
+#[test]
+fn type_mismatch() {
+    let pos = Instr::PushSign(nzSign::Pos);
+    assert_eq!(
+        run(&[int(1), pos, Instr::Add]),
+        fault(2, VmFault::TypeMismatch)
+    );
+    assert_eq!(
+        run(&[int(1), pos, Instr::And]),
+        fault(2, VmFault::TypeMismatch)
+    );
+    assert_eq!(
+        run(&[float(1.5), Instr::Not]),
+        fault(1, VmFault::TypeMismatch)
+    );
+    assert_eq!(run(&[pos, Instr::Sign]), fault(1, VmFault::TypeMismatch));
+    assert_eq!(
+        run(&[int(1), Instr::JumpIfPos(0)]),
+        fault(1, VmFault::TypeMismatch)
+    );
+}
+
+#[test]
+fn jumps_may_target_the_end_but_not_past_it() {
+    let program = [int(4), Instr::Jump(3), int(9), Instr::Jump(4)];
+    // Jump(3) skips int(9); Jump(4) == len() ends the program.
+    assert_eq!(run(&program), Ok(NzValue::Int(NzInt::new(4).unwrap())));
+    let past = [int(4), Instr::Jump(3), int(9)];
+    assert_eq!(run(&past), Ok(NzValue::Int(NzInt::new(4).unwrap())));
+    let bad = [int(4), Instr::Jump(4), int(9)];
+    assert_eq!(run(&bad), fault(1, VmFault::BadJump));
+}
+
+#[test]
+fn step_limit_stops_a_self_loop() {
+    let program = [Instr::Jump(0)];
+    assert_eq!(run_limited(&program, 1_000), fault(0, VmFault::StepLimit));
+    // The limit counts executed instructions: 3 fit exactly, 2 do not.
+    let straight = [int(1), int(2), Instr::Add];
+    assert_eq!(
+        run_limited(&straight, 3),
+        Ok(NzValue::Int(NzInt::new(3).unwrap()))
+    );
+    assert_eq!(run_limited(&straight, 2), fault(2, VmFault::StepLimit));
+}
+
+#[test]
+fn mixed_arithmetic_widens_the_int() {
+    assert_eq!(
+        run(&[int(7), float(2.0), Instr::Div]),
+        Ok(NzValue::Float(NzFloat::new(3.5).unwrap()))
+    );
+    assert_eq!(
+        run(&[float(0.5), int(2), Instr::Mul]),
+        Ok(NzValue::Float(NzFloat::new(1.0).unwrap()))
+    );
+    // Int / Int truncates instead.
+    assert_eq!(
+        run(&[int(7), int(2), Instr::Div]),
+        Ok(NzValue::Int(NzInt::new(3).unwrap()))
+    );
+    // A widened zero result is a Float error.
+    let r = run(&[int(2), float(2.0), Instr::Sub]);
+    assert!(
+        matches!(
+            r,
+            Err(VmError {
+                index: 2,
+                error: VmFault::Arith(Error::Float(_))
+            })
+        ),
+        "{r:?}"
+    );
+}
+
+#[test]
+fn sign_of_a_number() {
+    assert_eq!(
+        run(&[float(-0.25), Instr::Sign]),
+        Ok(NzValue::Sign(nzSign::Neg))
+    );
+    assert_eq!(
+        run(&[int(3), Instr::Sign, Instr::Not]),
+        Ok(NzValue::Sign(nzSign::Neg))
+    );
+}
+
+/// `a && b` as the module docs spell it: [a, JumpIfPos(skip), PushSign(Neg), Jump(end), b].
+fn and_then(a: Instr, b: &[Instr]) -> Vec<Instr> {
+    let end = 4 + b.len();
+    let mut program = vec![
+        a,
+        Instr::JumpIfPos(4),
+        Instr::PushSign(nzSign::Neg),
+        Instr::Jump(end),
+    ];
+    program.extend_from_slice(b);
+    program
+}
+
+#[test]
+fn short_circuit_and() {
+    let pos = Instr::PushSign(nzSign::Pos);
+    let neg = Instr::PushSign(nzSign::Neg);
+    assert_eq!(run(&and_then(pos, &[pos])), Ok(NzValue::Sign(nzSign::Pos)));
+    assert_eq!(run(&and_then(pos, &[neg])), Ok(NzValue::Sign(nzSign::Neg)));
+    assert_eq!(run(&and_then(neg, &[pos])), Ok(NzValue::Sign(nzSign::Neg)));
+    // A false left side never runs the right side, even one that would fault.
+    let faulty = [int(1), int(1), Instr::Sub, Instr::Sign];
+    assert_eq!(run(&and_then(neg, &faulty)), Ok(NzValue::Sign(nzSign::Neg)));
+    assert_eq!(
+        run(&and_then(pos, &faulty)),
+        fault(6, VmFault::Arith(Error::Int(NzError::ZeroResult)))
+    );
+}
+
+#[test]
+fn run_traced_records_every_completed_step() {
+    let one = |v| Some(NzValue::Int(NzInt::new(v).unwrap()));
+    let (trace, result) = run_traced(&[int(1), int(2), Instr::Add, Instr::Jump(5), int(9)], 100);
+    assert_eq!(result, Ok(NzValue::Int(NzInt::new(3).unwrap())));
+    let want = [
+        TraceStep {
+            index: 0,
+            pushed: one(1),
+        },
+        TraceStep {
+            index: 1,
+            pushed: one(2),
+        },
+        TraceStep {
+            index: 2,
+            pushed: one(3),
+        },
+        TraceStep {
+            index: 3,
+            pushed: None,
+        },
+    ];
+    assert_eq!(trace, want);
+}
+
+#[test]
+fn run_traced_stops_before_the_fault() {
+    let (trace, result) = run_traced(&[int(5), int(5), Instr::Sub, int(1)], 100);
+    assert_eq!(
+        result,
+        fault(2, VmFault::Arith(Error::Int(NzError::ZeroResult)))
+    );
+    assert_eq!(trace.iter().map(|s| s.index).collect::<Vec<_>>(), [0, 1]);
+    let (trace, result) = run_traced(&[Instr::Jump(0)], 3);
+    assert_eq!(result, fault(0, VmFault::StepLimit));
+    assert_eq!(trace.len(), 3);
+}